@@ -0,0 +1,40 @@
+//! With the `embed` feature, generates tables of every input and answer
+//! file in the repository, for `embedded.rs` to include.
+
+use std::{fmt::Write, path::Path};
+
+#[path = "src/stored.rs"]
+mod stored;
+
+/// The `((year, day), include_bytes!(..))` entries of the files in `root`.
+fn entries(root: &Path) -> String {
+	let mut res = String::new();
+	for ((year, day), file) in stored::files(root).unwrap() {
+		let path = file.canonicalize().unwrap();
+		writeln!(res, "\t(({}, {}), include_bytes!({:?})),", year, day, path)
+			.unwrap();
+	}
+	res
+}
+
+fn main() {
+	println!("cargo:rerun-if-changed=build.rs");
+	println!("cargo:rerun-if-changed=src/stored.rs");
+	if std::env::var_os("CARGO_FEATURE_EMBED").is_none() {
+		return;
+	}
+
+	let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+	let repo = Path::new(&manifest_dir).join("../..");
+	let mut code = String::new();
+	for (name, dir) in [("INPUTS", "inputs"), ("ANSWERS", "answers")] {
+		let root = repo.join(dir);
+		println!("cargo:rerun-if-changed={}", root.display());
+		writeln!(code, "static {}: &[((usize, usize), &[u8])] = &[", name).unwrap();
+		code.push_str(&entries(&root));
+		code.push_str("];\n");
+	}
+
+	let out_dir = std::env::var("OUT_DIR").unwrap();
+	std::fs::write(Path::new(&out_dir).join("embedded.rs"), code).unwrap();
+}
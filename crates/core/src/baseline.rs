@@ -0,0 +1,115 @@
+//! Named snapshots of a run's results, for comparing against while
+//! optimising.
+
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+
+use crate::{
+	common::RunResult,
+	history::{self, percent_change, Entry},
+	report::Reporter,
+};
+
+pub const BASELINE_DIR: &str = "./.aoc/baselines";
+
+pub fn path(name: &str) -> PathBuf {
+	PathBuf::from(BASELINE_DIR).join(format!("{}.json", name))
+}
+
+pub fn save(
+	name: &str,
+	results: &[RunResult],
+) -> Result<PathBuf, anyhow::Error> {
+	let path = path(name);
+	std::fs::create_dir_all(BASELINE_DIR)?;
+	let json = serde_json::to_string_pretty(&history::entries(results))?;
+	std::fs::write(&path, json)
+		.map_err(|err| anyhow!("Error writing {}: {}", path.display(), err))?;
+	Ok(path)
+}
+
+pub fn load(name: &str) -> Result<Vec<Entry>, anyhow::Error> {
+	let path = path(name);
+	let text = std::fs::read_to_string(&path)
+		.map_err(|err| anyhow!("Error reading {}: {}", path.display(), err))?;
+	serde_json::from_str(&text)
+		.map_err(|err| anyhow!("Error parsing {}: {}", path.display(), err))
+}
+
+/// Prints a table of `results` next to the baseline's timings of the same
+/// challenges, with the change between them.
+pub fn compare(
+	name: &str,
+	baseline: &[Entry],
+	results: &[RunResult],
+	reporter: &dyn Reporter,
+) {
+	let t = reporter.theme();
+	reporter.heading(&format!("Compared to baseline {}", name));
+
+	let width = results
+		.iter()
+		.map(|r| r.challenge.label().len())
+		.max()
+		.unwrap_or(0);
+	println!(
+		"{}",
+		t.label(&format!(
+			"{:width$}  {:>12}  {:>12}  {:>12}  {:>8}",
+			"challenge",
+			"baseline",
+			"current",
+			"delta",
+			"change",
+			width = width
+		))
+	);
+
+	for result in results {
+		let label = format!("{:width$}", result.challenge.label(), width = width);
+		let current = result.duration;
+		let Some(before) = baseline.iter().find(|e| e.is_for(result.challenge))
+		else {
+			println!(
+				"{}  {:>12}  {:>12.6}",
+				t.label(&label),
+				"-",
+				current.as_secs_f64()
+			);
+			continue;
+		};
+
+		let delta = current.as_secs_f64() - before.duration;
+		let change = percent_change(before.duration(), current);
+		let change_text = format!("{:>+7.1}%", change);
+		let change_text = if change > 0.0 {
+			t.fail(&change_text)
+		} else {
+			t.pass(&change_text)
+		};
+		let mut line = format!(
+			"{}  {:>12.6}  {:>12.6}  {:>+12.6}  {}",
+			t.label(&label),
+			before.duration,
+			current.as_secs_f64(),
+			delta,
+			change_text
+		);
+		let answer = result.result.as_ref().ok().copied();
+		if answer != before.answer {
+			let describe = |a: Option<u128>| {
+				a.map(|a| a.to_string()).unwrap_or("error".to_string())
+			};
+			line.push_str(&format!(
+				"  {}",
+				t.warn(&format!(
+					"answer changed: {} -> {}",
+					describe(before.answer),
+					describe(answer)
+				))
+			));
+		}
+		println!("{}", line);
+	}
+}
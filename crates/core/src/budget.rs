@@ -0,0 +1,75 @@
+//! Checking real-run durations against a runtime budget.
+
+use std::{cmp::Reverse, collections::BTreeMap, time::Duration};
+
+use crate::{common::RunResult, report::Reporter};
+
+/// How many of the slowest challenges to list.
+const OFFENDERS: usize = 10;
+
+/// Prints how the run's total time, and each year's, compares to the given
+/// budgets along with the slowest challenges. Returns whether any budget
+/// was exceeded.
+pub fn check(
+	results: &[RunResult],
+	total: Option<Duration>,
+	per_year: Option<Duration>,
+	reporter: &dyn Reporter,
+) -> bool {
+	let t = reporter.theme();
+	let mut exceeded = false;
+
+	reporter.heading("Runtime budget");
+
+	let mut line = |label: &str, spent: Duration, budget: Duration| {
+		let percent = spent.as_secs_f64() / budget.as_secs_f64() * 100.0;
+		let text = format!(
+			"{:.6}s of {} ({:.0}%)",
+			spent.as_secs_f64(),
+			humantime::format_duration(budget),
+			percent
+		);
+		let text = if spent > budget {
+			exceeded = true;
+			format!("{} {}", t.fail(&text), t.badge(" OVER "))
+		} else {
+			t.pass(&text)
+		};
+		println!("{} - {}", t.label(label), text);
+	};
+
+	if let Some(budget) = total {
+		line("Total", results.iter().map(|r| r.duration).sum(), budget);
+	}
+
+	if let Some(budget) = per_year {
+		let mut years = BTreeMap::<usize, Duration>::new();
+		for result in results {
+			*years.entry(result.challenge.year).or_default() += result.duration;
+		}
+		for (year, spent) in years {
+			line(&year.to_string(), spent, budget);
+		}
+	}
+
+	let spent: Duration = results.iter().map(|r| r.duration).sum();
+	let mut slowest = results.iter().collect::<Vec<&RunResult>>();
+	slowest.sort_by_key(|r| Reverse(r.duration));
+
+	reporter.heading("Slowest challenges");
+	for result in slowest.into_iter().take(OFFENDERS) {
+		let share = if spent.is_zero() {
+			0.0
+		} else {
+			result.duration.as_secs_f64() / spent.as_secs_f64() * 100.0
+		};
+		println!(
+			"{} - {:>12.6}s {}",
+			t.label(&result.challenge.label()),
+			result.duration.as_secs_f64(),
+			t.label(&format!("{:>5.1}%", share))
+		);
+	}
+
+	exceeded
+}
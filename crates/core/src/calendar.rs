@@ -0,0 +1,112 @@
+//! An advent calendar of each year's progress.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use crate::{common::Challenge, report::Reporter, runner, store, unlock};
+
+const DAYS_PER_ROW: usize = 5;
+
+/// What a day looks like on the calendar.
+#[derive(Default)]
+struct Day {
+	registered: bool,
+	/// Parts with a known answer.
+	stars: usize,
+	skipped: bool,
+	/// Some part's examples fail.
+	failing: bool,
+}
+
+impl Day {
+	fn cell(&self, day: usize, reporter: &dyn Reporter) -> String {
+		let t = reporter.theme();
+		let stars = match self.stars {
+			0 => "☆☆",
+			1 => "★☆",
+			_ => "★★",
+		};
+		let text = format!("{:0>2} {}", day, stars);
+		if self.failing {
+			format!("{}x", t.fail(&text))
+		} else if self.skipped {
+			format!("{}s", t.skip(&text))
+		} else if !self.registered {
+			format!("{} ", t.label(&text))
+		} else if self.stars == 2 {
+			format!("{} ", t.warn(&text))
+		} else {
+			format!("{} ", text)
+		}
+	}
+}
+
+/// Prints a grid of the event's days for every year with registered
+/// challenges, with the stars of each day taken from the answer store. Runs
+/// the examples to find the failing days.
+pub fn show(
+	challenges: &[&'static Challenge],
+	reporter: &dyn Reporter,
+) -> Result<(), anyhow::Error> {
+	let t = reporter.theme();
+	let failing = runner::unit_tests(challenges)
+		.into_iter()
+		.filter(|r| !r.passed())
+		.map(|r| r.challenge)
+		.collect::<HashSet<&Challenge>>();
+
+	let years = challenges
+		.iter()
+		.map(|c| c.year)
+		.collect::<BTreeSet<usize>>();
+	let mut total = 0;
+	for year in years {
+		let mut days = BTreeMap::<usize, Day>::new();
+		let event_days = unlock::days_in(year);
+		for day in 1..=event_days {
+			let stars = store::read_answers(year, day)?
+				.iter()
+				.filter(|answer| answer.is_some())
+				.count();
+			days.insert(
+				day,
+				Day {
+					stars,
+					..Default::default()
+				},
+			);
+		}
+		for challenge in challenges.iter().filter(|c| c.year == year) {
+			let Some(day) = days.get_mut(&challenge.day) else {
+				continue;
+			};
+			day.registered = true;
+			day.skipped |= challenge.skip;
+			day.failing |= failing.contains(challenge);
+		}
+
+		let stars: usize = days.values().map(|d| d.stars).sum();
+		total += stars;
+		reporter.heading(&format!(
+			"{} {}",
+			year,
+			t.label(&format!("{}/{} ★", stars, event_days * 2))
+		));
+		let cells = days
+			.iter()
+			.map(|(day, d)| d.cell(*day, reporter))
+			.collect::<Vec<String>>();
+		for row in cells.chunks(DAYS_PER_ROW) {
+			println!("  {}", row.join(" "));
+		}
+	}
+
+	println!();
+	reporter.detail(&format!(
+		"{} stars in total, {} failing examples, {} skipped",
+		total,
+		t.fail("x"),
+		t.skip("s")
+	));
+
+	Ok(())
+}
@@ -0,0 +1,66 @@
+//! Per-thread capture of anything a challenge prints.
+//!
+//! Challenges run in parallel, so output printed from inside a solution would
+//! interleave with the report. The `print!` and `println!` macros defined here
+//! shadow the std ones for every module declared after this one, and in year
+//! crates that import them with `#[macro_use] extern crate aoc_core;`: while
+//! a [`capture`] is active on the current thread they append to its buffer,
+//! otherwise they behave exactly like the std macros.
+//!
+//! Output printed from threads the solution spawns itself (e.g. inside a
+//! rayon iterator) is not captured.
+
+use std::{cell::RefCell, fmt::Write};
+
+thread_local! {
+	static BUFFER: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Puts back the capture that was active before one started when dropped,
+/// so a challenge that panics doesn't leave its thread capturing.
+struct Restore(Option<String>);
+
+impl Drop for Restore {
+	fn drop(&mut self) {
+		let outer = self.0.take();
+		BUFFER.with(|b| b.replace(outer));
+	}
+}
+
+/// Runs `f`, returning its result along with everything it printed.
+pub fn capture<R>(f: impl FnOnce() -> R) -> (R, String) {
+	let _restore = Restore(BUFFER.with(|b| b.replace(Some(String::new()))));
+	let res = f();
+	let output = BUFFER.with(|b| b.take()).unwrap_or_default();
+	(res, output)
+}
+
+pub fn write(args: std::fmt::Arguments) {
+	let captured = BUFFER.with(|b| match b.borrow_mut().as_mut() {
+		Some(buf) => {
+			let _ = buf.write_fmt(args);
+			true
+		}
+		None => false,
+	});
+	if !captured {
+		std::print!("{}", args);
+	}
+}
+
+#[macro_export]
+macro_rules! print {
+	($($arg:tt)*) => {
+		$crate::capture::write(format_args!($($arg)*))
+	};
+}
+
+#[macro_export]
+macro_rules! println {
+	() => {
+		$crate::capture::write(format_args!("\n"))
+	};
+	($($arg:tt)*) => {
+		$crate::capture::write(format_args!("{}\n", format_args!($($arg)*)))
+	};
+}
@@ -0,0 +1,259 @@
+use crate::{
+	capture::capture,
+	memory::{self, MemoryStats},
+	progress, store,
+};
+use anyhow::anyhow;
+use core::cmp::Ordering;
+use std::{
+	collections::HashSet,
+	sync::mpsc,
+	time::{Duration, Instant},
+};
+
+pub fn all_challenges() -> Result<Vec<&'static Challenge>, anyhow::Error> {
+	let mut set = HashSet::new();
+
+	for challenge in inventory::iter::<Challenge> {
+		let challenge: &Challenge = challenge;
+		if !set.insert(challenge) {
+			return Err(anyhow!("Duplicate definition of: {}", challenge.label()));
+		}
+	}
+
+	let mut res = set.into_iter().collect::<Vec<&Challenge>>();
+	res.sort();
+
+	Ok(res)
+}
+
+#[derive(Hash)]
+pub struct Challenge {
+	pub year: usize,
+	pub day: usize,
+	pub part: usize,
+	pub f: ChallengeFn,
+	pub unit_tests: &'static [(&'static str, u128)],
+	pub skip: bool,
+}
+
+inventory::collect!(Challenge);
+
+impl Challenge {
+	pub fn label(&self) -> String {
+		format!(
+			"{} :: Day {:0>2} :: Part {}",
+			self.year, self.day, self.part
+		)
+	}
+
+	pub fn unit_test(&'static self) -> TestResults {
+		let (results, outputs) = self
+			.unit_tests
+			.iter()
+			.map(|(input, expected)| {
+				let (res, output) = capture(|| (self.f)(input));
+				let res = match res {
+					Ok(got) => {
+						if *expected == got {
+							UnitTestResult::Equal
+						} else {
+							UnitTestResult::NotEqual {
+								input: input.to_string(),
+								expected: *expected,
+								got,
+							}
+						}
+					}
+					Err(err) => UnitTestResult::Other(err),
+				};
+				(res, output)
+			})
+			.unzip();
+		TestResults {
+			challenge: &self,
+			results,
+			outputs,
+		}
+	}
+
+	pub fn run_on_file(&'static self) -> Result<RunResult, anyhow::Error> {
+		let input = store::read_input(self.year, self.day)?;
+		let expected = store::known_answer(self)?;
+
+		let start = Instant::now();
+		let ((result, memory), output) =
+			capture(|| memory::measure(|| (self.f)(&input)));
+		let duration = start.elapsed();
+
+		Ok(RunResult {
+			challenge: self,
+			duration,
+			result,
+			output,
+			memory,
+			expected,
+			timed_out: false,
+		})
+	}
+
+	/// Like [`Challenge::run_on_file`], but gives up after `timeout`. The
+	/// challenge keeps running on its own thread until the process exits.
+	pub fn run_with_timeout(
+		&'static self,
+		timeout: Duration,
+	) -> Result<RunResult, anyhow::Error> {
+		let (tx, rx) = mpsc::channel();
+		let progress = progress::current();
+		std::thread::spawn(move || {
+			if let Some(progress) = progress {
+				progress.attach();
+			}
+			let _ = tx.send(self.run_on_file());
+		});
+		match rx.recv_timeout(timeout) {
+			Ok(res) => res,
+			Err(_) => Ok(RunResult {
+				challenge: self,
+				duration: timeout,
+				result: Err(anyhow!(
+					"Timed out after {}",
+					humantime::format_duration(timeout)
+				)),
+				output: String::new(),
+				memory: None,
+				expected: store::known_answer(self)?,
+				timed_out: true,
+			}),
+		}
+	}
+}
+
+/// The outcome of running a challenge on its real input.
+pub struct RunResult {
+	pub challenge: &'static Challenge,
+	pub duration: Duration,
+	pub result: ChallengeResult,
+	/// Everything the challenge printed while running.
+	pub output: String,
+	/// Heap usage of the run, when built with the `memory` feature.
+	pub memory: Option<MemoryStats>,
+	/// The answer in the answer store, if it's known.
+	pub expected: Option<u128>,
+	pub timed_out: bool,
+}
+
+impl RunResult {
+	/// Whether the challenge gave an answer other than the known one.
+	pub fn wrong_answer(&self) -> bool {
+		match (&self.result, self.expected) {
+			(Ok(got), Some(expected)) => *got != expected,
+			_ => false,
+		}
+	}
+}
+
+impl PartialEq for Challenge {
+	fn eq(&self, other: &Self) -> bool {
+		self.year == other.year && self.day == other.day && self.part == other.part
+	}
+}
+
+impl Eq for Challenge {}
+
+impl PartialOrd for Challenge {
+	fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+		match self.year.partial_cmp(&other.year) {
+			Some(Ordering::Equal) => {}
+			ord => return ord,
+		}
+		match self.day.partial_cmp(&other.day) {
+			Some(Ordering::Equal) => {}
+			ord => return ord,
+		}
+		match self.part.partial_cmp(&other.part) {
+			Some(Ordering::Equal) => {}
+			ord => return ord,
+		}
+		Some(Ordering::Equal)
+	}
+}
+
+impl Ord for Challenge {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.partial_cmp(other).unwrap()
+	}
+}
+
+pub struct TestResults {
+	pub challenge: &'static Challenge,
+	pub results: Vec<UnitTestResult>,
+	/// What each unit test printed, in the same order as `results`.
+	pub outputs: Vec<String>,
+}
+
+impl TestResults {
+	pub fn n_passed(&self) -> usize {
+		self.results.iter().filter(|r| r.equal()).count()
+	}
+
+	pub fn passed(&self) -> bool {
+		self.challenge.skip || self.n_passed() == self.results.len()
+	}
+
+	/// The captured output of every unit test that printed something,
+	/// labelled by test index.
+	pub fn output(&self) -> String {
+		let mut res = String::new();
+		for (i, output) in self.outputs.iter().enumerate() {
+			if output.is_empty() {
+				continue;
+			}
+			res.push_str(&format!("{}Output of test {}:\n", idt(1), i));
+			res.push_str(&indented(output, 2));
+		}
+		res
+	}
+}
+
+#[derive(Debug)]
+pub enum UnitTestResult {
+	Equal,
+	NotEqual {
+		input: String,
+		expected: u128,
+		got: u128,
+	},
+	Other(anyhow::Error),
+}
+
+impl UnitTestResult {
+	pub fn equal(&self) -> bool {
+		match self {
+			Self::Equal => true,
+			_ => false,
+		}
+	}
+}
+
+pub type ChallengeFn = fn(&str) -> ChallengeResult;
+pub type ChallengeResult = Result<u128, anyhow::Error>;
+
+fn idt(level: usize) -> String {
+	let mut s = String::new();
+	for _ in 0..level {
+		s += "  ";
+	}
+	s
+}
+
+/// Indents every line of `text` by `level`, ending with a newline.
+pub fn indented(text: &str, level: usize) -> String {
+	let mut res = String::new();
+	for line in text.trim_end().lines() {
+		res.push_str(&idt(level));
+		res.push_str(line);
+		res.push('\n');
+	}
+	res
+}
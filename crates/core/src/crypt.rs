@@ -0,0 +1,207 @@
+//! Encrypting inputs and answers, so they can be versioned in a public
+//! repository without publishing them.
+//!
+//! An encrypted file keeps its path and starts with [`MAGIC`], followed by
+//! the salt its key was derived from, the nonce and the AES-256-GCM
+//! ciphertext. The key is derived with Argon2 from a passphrase, taken from
+//! `AOC_PASSPHRASE` or the key file [`KEY_PATH`]. [`read_to_string`] reads
+//! both encrypted and plain files, so the rest of the store doesn't care which
+//! it's given.
+
+use std::{
+	collections::HashMap,
+	path::{Path, PathBuf},
+	sync::Mutex,
+};
+
+use aes_gcm::{
+	aead::{rand_core::RngCore, Aead, AeadCore, KeyInit, OsRng},
+	Aes256Gcm, Key, Nonce,
+};
+use anyhow::anyhow;
+use argon2::Argon2;
+use once_cell::sync::Lazy;
+
+use crate::{report::Reporter, stored};
+
+pub const KEY_PATH: &str = "./.aoc/key";
+pub const PASSPHRASE_ENV: &str = "AOC_PASSPHRASE";
+pub const MAGIC: &[u8] = b"aoc-encrypted v1\n";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+/// Where the stored files that get encrypted live.
+const ROOTS: [&str; 2] = ["./inputs", "./answers"];
+
+type Salt = [u8; SALT_LEN];
+type KeyCache = HashMap<(String, Salt), Key<Aes256Gcm>>;
+
+/// Derived keys by passphrase and salt. Deriving is slow on purpose, and
+/// every file encrypted by the same `encrypt` shares a salt.
+static KEYS: Lazy<Mutex<KeyCache>> = Lazy::new(Default::default);
+
+/// The passphrase from the environment or the key file, if there is one.
+pub fn passphrase() -> Result<Option<String>, anyhow::Error> {
+	if let Ok(passphrase) = std::env::var(PASSPHRASE_ENV) {
+		if passphrase.trim().is_empty() {
+			return Err(anyhow!("{} is empty", PASSPHRASE_ENV));
+		}
+		return Ok(Some(passphrase.trim().to_string()));
+	}
+	match std::fs::read_to_string(KEY_PATH) {
+		Ok(passphrase) if passphrase.trim().is_empty() => {
+			Err(anyhow!("{} is empty", KEY_PATH))
+		}
+		Ok(passphrase) => Ok(Some(passphrase.trim().to_string())),
+		Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+		Err(err) => Err(anyhow!("Error reading {}: {}", KEY_PATH, err)),
+	}
+}
+
+fn require_passphrase() -> Result<String, anyhow::Error> {
+	passphrase()?.ok_or_else(|| {
+		anyhow!(
+			"No passphrase, set {} or write one to {}",
+			PASSPHRASE_ENV,
+			KEY_PATH
+		)
+	})
+}
+
+fn derive(
+	passphrase: &str,
+	salt: Salt,
+) -> Result<Key<Aes256Gcm>, anyhow::Error> {
+	let mut keys = KEYS.lock().unwrap();
+	if let Some(key) = keys.get(&(passphrase.to_string(), salt)) {
+		return Ok(*key);
+	}
+	let mut key = Key::<Aes256Gcm>::default();
+	Argon2::default()
+		.hash_password_into(passphrase.as_bytes(), &salt, &mut key)
+		.map_err(|err| anyhow!("Error deriving the key: {}", err))?;
+	keys.insert((passphrase.to_string(), salt), key);
+	Ok(key)
+}
+
+pub fn is_encrypted(data: &[u8]) -> bool {
+	data.starts_with(MAGIC)
+}
+
+/// A fresh random salt, to share between the files encrypted together.
+pub fn salt() -> Salt {
+	let mut salt = [0; SALT_LEN];
+	OsRng.fill_bytes(&mut salt);
+	salt
+}
+
+pub fn encrypt(
+	plaintext: &[u8],
+	passphrase: &str,
+	salt: Salt,
+) -> Result<Vec<u8>, anyhow::Error> {
+	let cipher = Aes256Gcm::new(&derive(passphrase, salt)?);
+	let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+	let ciphertext = cipher
+		.encrypt(&nonce, plaintext)
+		.map_err(|err| anyhow!("Error encrypting: {}", err))?;
+	Ok([MAGIC, &salt, &nonce, &ciphertext].concat())
+}
+
+pub fn decrypt(
+	data: &[u8],
+	passphrase: &str,
+) -> Result<Vec<u8>, anyhow::Error> {
+	let data = data
+		.strip_prefix(MAGIC)
+		.ok_or_else(|| anyhow!("Not encrypted"))?;
+	if data.len() < SALT_LEN + NONCE_LEN {
+		return Err(anyhow!("Truncated encrypted file"));
+	}
+	let (salt, data) = data.split_at(SALT_LEN);
+	let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+	let cipher = Aes256Gcm::new(&derive(passphrase, salt.try_into().unwrap())?);
+	cipher
+		.decrypt(Nonce::from_slice(nonce), ciphertext)
+		.map_err(|_| anyhow!("Wrong passphrase, or the file is corrupted"))
+}
+
+/// The text of a stored file's contents, decrypted if they're encrypted.
+pub fn decode(data: Vec<u8>) -> std::io::Result<String> {
+	let data = if is_encrypted(&data) {
+		require_passphrase()
+			.and_then(|passphrase| decrypt(&data, &passphrase))
+			.map_err(|err| std::io::Error::other(err.to_string()))?
+	} else {
+		data
+	};
+	String::from_utf8(data).map_err(|err| {
+		std::io::Error::new(std::io::ErrorKind::InvalidData, err.to_string())
+	})
+}
+
+/// Like [`std::fs::read_to_string`], but decrypts encrypted files.
+pub fn read_to_string(path: &Path) -> std::io::Result<String> {
+	decode(std::fs::read(path)?)
+}
+
+/// The inputs and answer files on disk, of `year` and `day` if given.
+pub fn stored_files(
+	year: Option<usize>,
+	day: Option<usize>,
+) -> Result<Vec<PathBuf>, anyhow::Error> {
+	let mut files = Vec::new();
+	for root in ROOTS {
+		let stored = stored::files(Path::new(root))
+			.map_err(|err| anyhow!("Error reading {}: {}", root, err))?;
+		files.extend(stored.into_iter().filter_map(|((y, d), file)| {
+			(year.is_none_or(|year| year == y) && day.is_none_or(|day| day == d))
+				.then_some(file)
+		}));
+	}
+	files.sort();
+	Ok(files)
+}
+
+/// Encrypts or decrypts the stored files of `year` and `day` in place,
+/// leaving those that already are alone.
+pub fn convert(
+	year: Option<usize>,
+	day: Option<usize>,
+	encrypting: bool,
+	reporter: &dyn Reporter,
+) -> Result<(), anyhow::Error> {
+	let passphrase = require_passphrase()?;
+	let salt = salt();
+	let (mut converted, mut unchanged) = (0, 0);
+	for path in stored_files(year, day)? {
+		let data = std::fs::read(&path)
+			.map_err(|err| anyhow!("Error reading {}: {}", path.display(), err))?;
+		if is_encrypted(&data) == encrypting {
+			unchanged += 1;
+			continue;
+		}
+		let data = if encrypting {
+			encrypt(&data, &passphrase, salt)
+		} else {
+			decrypt(&data, &passphrase)
+		}
+		.map_err(|err| anyhow!("{}: {}", path.display(), err))?;
+		// written next to it first, so an interrupted run can't lose the file
+		let mut temp = path.clone().into_os_string();
+		temp.push(".tmp");
+		std::fs::write(&temp, data)
+			.and_then(|_| std::fs::rename(&temp, &path))
+			.map_err(|err| anyhow!("Error writing {}: {}", path.display(), err))?;
+		converted += 1;
+	}
+
+	let done = if encrypting { "encrypted" } else { "decrypted" };
+	reporter.detail(&format!(
+		"{} {} file{}, {} already were",
+		done,
+		converted,
+		if converted == 1 { "" } else { "s" },
+		unchanged
+	));
+	Ok(())
+}
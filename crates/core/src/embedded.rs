@@ -0,0 +1,31 @@
+//! Inputs and answers built into the binary with the `embed` feature, so it
+//! runs from anywhere. They're exactly the files in `./inputs` and
+//! `./answers` when it was built, still encrypted if they were.
+
+#[cfg(feature = "embed")]
+include!(concat!(env!("OUT_DIR"), "/embedded.rs"));
+
+#[cfg(not(feature = "embed"))]
+static INPUTS: &[((usize, usize), &[u8])] = &[];
+#[cfg(not(feature = "embed"))]
+static ANSWERS: &[((usize, usize), &[u8])] = &[];
+
+/// The file of `year` and `day` in one of the tables.
+pub fn find(
+	files: &'static [((usize, usize), &'static [u8])],
+	year: usize,
+	day: usize,
+) -> Option<&'static [u8]> {
+	files
+		.iter()
+		.find(|(key, _)| *key == (year, day))
+		.map(|(_, data)| *data)
+}
+
+pub fn input(year: usize, day: usize) -> Option<&'static [u8]> {
+	find(INPUTS, year, day)
+}
+
+pub fn answers(year: usize, day: usize) -> Option<&'static [u8]> {
+	find(ANSWERS, year, day)
+}
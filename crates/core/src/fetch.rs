@@ -0,0 +1,86 @@
+//! Downloading puzzle pages, inputs and leaderboards from the Advent of Code
+//! site, or any stand-in serving the same paths.
+
+use std::time::Duration;
+
+use anyhow::anyhow;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Where the session cookie is read from when it isn't given otherwise.
+pub const SESSION_PATH: &str = "./.aoc/session";
+/// The site asks automated tools to say who they are.
+const USER_AGENT: &str = "github.com/mcpar-land/adventofcode";
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+pub struct Client {
+	base_url: String,
+	session: Option<String>,
+	agent: ureq::Agent,
+}
+
+impl Client {
+	/// A client for the site at `base_url`, logged in with the `session`
+	/// cookie if there is one.
+	pub fn new(base_url: &str, session: Option<String>) -> Self {
+		Self {
+			base_url: base_url.trim_end_matches('/').to_string(),
+			session,
+			agent: ureq::AgentBuilder::new()
+				.timeout(TIMEOUT)
+				.user_agent(USER_AGENT)
+				.build(),
+		}
+	}
+
+	/// The session cookie saved in [`SESSION_PATH`], if any.
+	pub fn saved_session() -> Result<Option<String>, anyhow::Error> {
+		match std::fs::read_to_string(SESSION_PATH) {
+			Ok(session) => Ok(Some(session.trim().to_string())),
+			Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+			Err(err) => Err(anyhow!("Error reading {}: {}", SESSION_PATH, err)),
+		}
+	}
+
+	pub fn url(&self, path: &str) -> String {
+		format!("{}{}", self.base_url, path)
+	}
+
+	fn get(&self, path: &str) -> Result<String, anyhow::Error> {
+		let url = self.url(path);
+		let mut request = self.agent.get(&url);
+		if let Some(session) = &self.session {
+			request = request.set("Cookie", &format!("session={}", session));
+		}
+		request
+			.call()
+			.map_err(|err| anyhow!("Error fetching {}: {}", url, err))?
+			.into_string()
+			.map_err(|err| anyhow!("Error reading {}: {}", url, err))
+	}
+
+	pub fn input(
+		&self,
+		year: usize,
+		day: usize,
+	) -> Result<String, anyhow::Error> {
+		self.get(&format!("/{}/day/{}/input", year, day))
+	}
+
+	/// The HTML of a day's puzzle page.
+	pub fn puzzle(
+		&self,
+		year: usize,
+		day: usize,
+	) -> Result<String, anyhow::Error> {
+		self.get(&format!("/{}/day/{}", year, day))
+	}
+
+	/// The JSON of a private leaderboard.
+	pub fn leaderboard(
+		&self,
+		year: usize,
+		id: u64,
+	) -> Result<String, anyhow::Error> {
+		self.get(&format!("/{}/leaderboard/private/view/{}.json", year, id))
+	}
+}
@@ -0,0 +1,249 @@
+//! A local log of every real run, for spotting performance regressions.
+//!
+//! Each run appends one JSON line per challenge to [`HISTORY_PATH`]. The
+//! `history` command compares consecutive successful runs on this machine.
+
+use std::{
+	fs::OpenOptions,
+	io::Write,
+	path::Path,
+	process::Command,
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	common::{Challenge, RunResult},
+	report::Reporter,
+};
+
+pub const HISTORY_PATH: &str = "./.aoc/history.jsonl";
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Entry {
+	pub year: usize,
+	pub day: usize,
+	pub part: usize,
+	pub answer: Option<u128>,
+	pub error: Option<String>,
+	/// Run time in seconds.
+	pub duration: f64,
+	pub commit: Option<String>,
+	pub machine: String,
+	/// Seconds since the unix epoch.
+	pub timestamp: u64,
+}
+
+impl Entry {
+	pub fn is_for(&self, challenge: &Challenge) -> bool {
+		self.year == challenge.year
+			&& self.day == challenge.day
+			&& self.part == challenge.part
+	}
+
+	pub fn duration(&self) -> Duration {
+		Duration::from_secs_f64(self.duration)
+	}
+}
+
+/// The short hash of the checked out commit, if we're in a git repository.
+pub fn git_commit() -> Option<String> {
+	let output = Command::new("git")
+		.args(["rev-parse", "--short", "HEAD"])
+		.output()
+		.ok()?;
+	if !output.status.success() {
+		return None;
+	}
+	Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Something identifying this machine, so timings from different machines
+/// aren't compared against each other.
+pub fn machine_id() -> String {
+	std::fs::read_to_string("/etc/hostname")
+		.ok()
+		.map(|s| s.trim().to_string())
+		.filter(|s| !s.is_empty())
+		.or_else(|| std::env::var("HOSTNAME").ok())
+		.or_else(|| std::env::var("COMPUTERNAME").ok())
+		.unwrap_or_else(|| "unknown".to_string())
+}
+
+pub fn now() -> u64 {
+	SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map(|d| d.as_secs())
+		.unwrap_or(0)
+}
+
+/// Entries for `results`, stamped with the current commit, machine and time.
+pub fn entries(results: &[RunResult]) -> Vec<Entry> {
+	let commit = git_commit();
+	let machine = machine_id();
+	let timestamp = now();
+
+	results
+		.iter()
+		.map(|result| {
+			let (answer, error) = match &result.result {
+				Ok(answer) => (Some(*answer), None),
+				Err(err) => (None, Some(err.to_string())),
+			};
+			Entry {
+				year: result.challenge.year,
+				day: result.challenge.day,
+				part: result.challenge.part,
+				answer,
+				error,
+				duration: result.duration.as_secs_f64(),
+				commit: commit.clone(),
+				machine: machine.clone(),
+				timestamp,
+			}
+		})
+		.collect()
+}
+
+pub fn append(results: &[RunResult]) -> Result<(), anyhow::Error> {
+	let mut lines = String::new();
+	for entry in entries(results) {
+		lines.push_str(&serde_json::to_string(&entry)?);
+		lines.push('\n');
+	}
+
+	if let Some(parent) = Path::new(HISTORY_PATH).parent() {
+		std::fs::create_dir_all(parent)?;
+	}
+	OpenOptions::new()
+		.create(true)
+		.append(true)
+		.open(HISTORY_PATH)
+		.and_then(|mut file| file.write_all(lines.as_bytes()))
+		.map_err(|err| anyhow!("Error writing {}: {}", HISTORY_PATH, err))
+}
+
+/// Every recorded entry, oldest first. A missing history is empty.
+pub fn load() -> Result<Vec<Entry>, anyhow::Error> {
+	let text = match std::fs::read_to_string(HISTORY_PATH) {
+		Ok(text) => text,
+		Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+			return Ok(Vec::new())
+		}
+		Err(err) => return Err(anyhow!("Error reading {}: {}", HISTORY_PATH, err)),
+	};
+	text
+		.lines()
+		.filter(|line| !line.trim().is_empty())
+		.enumerate()
+		.map(|(i, line)| {
+			serde_json::from_str(line)
+				.map_err(|err| anyhow!("{} line {}: {}", HISTORY_PATH, i + 1, err))
+		})
+		.collect()
+}
+
+/// The successful runs of `challenge` on this machine, oldest first.
+pub fn timings<'a>(
+	entries: &'a [Entry],
+	challenge: &Challenge,
+	machine: &str,
+) -> Vec<&'a Entry> {
+	entries
+		.iter()
+		.filter(|e| e.is_for(challenge) && e.machine == machine)
+		.filter(|e| e.error.is_none())
+		.collect()
+}
+
+/// Percent change from `before` to `after`.
+pub fn percent_change(before: Duration, after: Duration) -> f64 {
+	let before = before.as_secs_f64();
+	if before == 0.0 {
+		return 0.0;
+	}
+	(after.as_secs_f64() - before) / before * 100.0
+}
+
+fn sparkline(durations: &[Duration]) -> String {
+	const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+	let max = durations.iter().max().cloned().unwrap_or_default();
+	let min = durations.iter().min().cloned().unwrap_or_default();
+	let range = (max - min).as_secs_f64();
+	durations
+		.iter()
+		.map(|d| {
+			if range == 0.0 {
+				return BARS[0];
+			}
+			let frac = (*d - min).as_secs_f64() / range;
+			BARS[((frac * 7.0).round() as usize).min(7)]
+		})
+		.collect()
+}
+
+/// Prints the trend of the last `last` runs of each challenge, flagging ones
+/// whose latest run is more than `threshold` percent slower than the run
+/// before it.
+pub fn show(
+	challenges: &[&'static Challenge],
+	reporter: &dyn Reporter,
+	threshold: f64,
+	last: usize,
+) -> Result<(), anyhow::Error> {
+	let t = reporter.theme();
+	let entries = load()?;
+	let machine = machine_id();
+	let mut regressions = 0;
+
+	for challenge in challenges {
+		let runs = timings(&entries, challenge, &machine);
+		let label = t.label(&challenge.label());
+		if runs.is_empty() {
+			println!("{} - {}", label, t.label("no runs"));
+			continue;
+		}
+		let recent = &runs[runs.len().saturating_sub(last.max(1))..];
+		let durations = recent.iter().map(|e| e.duration()).collect::<Vec<_>>();
+		let latest = runs[runs.len() - 1];
+		let mut line = format!(
+			"{} - {} {} {}",
+			label,
+			sparkline(&durations),
+			format_args!("{:.6}s", latest.duration),
+			t.label(&format!("({} runs)", runs.len())),
+		);
+		if runs.len() > 1 {
+			let previous = runs[runs.len() - 2];
+			let change = percent_change(previous.duration(), latest.duration());
+			let change_text = format!("{:+.1}% vs {:.6}s", change, previous.duration);
+			if change > threshold {
+				regressions += 1;
+				line.push_str(&format!(
+					" {} {}",
+					t.fail(&change_text),
+					t.badge(" SLOWER ")
+				));
+			} else if change < 0.0 {
+				line.push_str(&format!(" {}", t.pass(&change_text)));
+			} else {
+				line.push_str(&format!(" {}", t.label(&change_text)));
+			}
+		}
+		if latest.answer != runs[runs.len().saturating_sub(2)].answer {
+			line.push_str(&format!(" {}", t.warn("answer changed")));
+		}
+		println!("{}", line);
+	}
+
+	if regressions > 0 {
+		reporter.warning(&format!(
+			"{} challenge(s) got more than {}% slower",
+			regressions, threshold
+		));
+	}
+
+	Ok(())
+}
@@ -0,0 +1,229 @@
+//! A local journal of how long each part took to solve.
+//!
+//! [`JOURNAL_PATH`] gets one JSON line the first time each of these happens:
+//! a day is scaffolded, a part's examples pass, and a part gives its known
+//! answer. The `journal` command reports the time from scaffolding to each
+//! of the others.
+
+use std::{
+	collections::BTreeMap, fs::OpenOptions, io::Write, path::Path, time::Duration,
+};
+
+use anyhow::anyhow;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+	common::{Challenge, RunResult, TestResults},
+	report::Reporter,
+};
+
+pub const JOURNAL_PATH: &str = "./.aoc/journal.jsonl";
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum Event {
+	Scaffolded,
+	ExamplesPassed,
+	Answered,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct Record {
+	pub year: usize,
+	pub day: usize,
+	/// `None` for events of the whole day.
+	pub part: Option<usize>,
+	pub event: Event,
+	/// Seconds since the unix epoch.
+	pub timestamp: u64,
+}
+
+impl Record {
+	pub fn new(challenge: &Challenge, event: Event, timestamp: u64) -> Self {
+		Self {
+			year: challenge.year,
+			day: challenge.day,
+			part: Some(challenge.part),
+			event,
+			timestamp,
+		}
+	}
+
+	fn same_event(&self, other: &Record) -> bool {
+		self.year == other.year
+			&& self.day == other.day
+			&& self.part == other.part
+			&& self.event == other.event
+	}
+}
+
+/// Every record, oldest first. A missing journal is empty.
+pub fn load() -> Result<Vec<Record>, anyhow::Error> {
+	load_from(Path::new(JOURNAL_PATH))
+}
+
+/// Like [`load`], from the journal at `path`.
+pub fn load_from(path: &Path) -> Result<Vec<Record>, anyhow::Error> {
+	let text = match std::fs::read_to_string(path) {
+		Ok(text) => text,
+		Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+			return Ok(Vec::new())
+		}
+		Err(err) => {
+			return Err(anyhow!("Error reading {}: {}", path.display(), err))
+		}
+	};
+	text
+		.lines()
+		.filter(|line| !line.trim().is_empty())
+		.enumerate()
+		.map(|(i, line)| {
+			serde_json::from_str(line)
+				.map_err(|err| anyhow!("{} line {}: {}", path.display(), i + 1, err))
+		})
+		.collect()
+}
+
+/// Appends the records whose events aren't in the journal yet.
+pub fn record(records: Vec<Record>) -> Result<(), anyhow::Error> {
+	record_to(Path::new(JOURNAL_PATH), records)
+}
+
+/// Like [`record`], to the journal at `path`.
+pub fn record_to(
+	path: &Path,
+	records: Vec<Record>,
+) -> Result<(), anyhow::Error> {
+	let existing = load_from(path)?;
+	let mut lines = String::new();
+	let mut added = Vec::<Record>::new();
+	for record in records {
+		if existing.iter().chain(&added).any(|r| r.same_event(&record)) {
+			continue;
+		}
+		lines.push_str(&serde_json::to_string(&record)?);
+		lines.push('\n');
+		added.push(record);
+	}
+	if lines.is_empty() {
+		return Ok(());
+	}
+
+	if let Some(parent) = path.parent() {
+		std::fs::create_dir_all(parent)?;
+	}
+	OpenOptions::new()
+		.create(true)
+		.append(true)
+		.open(path)
+		.and_then(|mut file| file.write_all(lines.as_bytes()))
+		.map_err(|err| anyhow!("Error writing {}: {}", path.display(), err))
+}
+
+/// Records for the challenges whose examples passed, skipped ones aside.
+pub fn examples_passed(results: &[TestResults], timestamp: u64) -> Vec<Record> {
+	results
+		.iter()
+		.filter(|r| !r.challenge.skip && r.passed())
+		.map(|r| Record::new(r.challenge, Event::ExamplesPassed, timestamp))
+		.collect()
+}
+
+/// Records for the runs that gave their known answer.
+pub fn answered(results: &[RunResult], timestamp: u64) -> Vec<Record> {
+	results
+		.iter()
+		.filter(|r| r.expected.is_some() && r.result.is_ok() && !r.wrong_answer())
+		.map(|r| Record::new(r.challenge, Event::Answered, timestamp))
+		.collect()
+}
+
+/// How long after its day was scaffolded a part's examples passed and its
+/// answer was found.
+struct Solve {
+	challenge: &'static Challenge,
+	examples: Option<Duration>,
+	answer: Option<Duration>,
+}
+
+fn format_duration(duration: Duration) -> String {
+	humantime::format_duration(Duration::from_secs(duration.as_secs()))
+		.to_string()
+}
+
+/// Prints how long each part of the scaffolded days took to solve, ranked
+/// against the same part of the other days of its year.
+pub fn show(
+	challenges: &[&'static Challenge],
+	reporter: &dyn Reporter,
+) -> Result<(), anyhow::Error> {
+	let t = reporter.theme();
+	let records = load()?;
+	let find = |challenge: &Challenge, part: Option<usize>, event: Event| {
+		records
+			.iter()
+			.find(|r| {
+				r.year == challenge.year
+					&& r.day == challenge.day
+					&& r.part == part
+					&& r.event == event
+			})
+			.map(|r| r.timestamp)
+	};
+
+	let mut years = BTreeMap::<usize, Vec<Solve>>::new();
+	for &challenge in challenges {
+		let Some(scaffolded) = find(challenge, None, Event::Scaffolded) else {
+			continue;
+		};
+		let since = |event| {
+			find(challenge, Some(challenge.part), event)
+				.map(|at: u64| Duration::from_secs(at.saturating_sub(scaffolded)))
+		};
+		years.entry(challenge.year).or_default().push(Solve {
+			challenge,
+			examples: since(Event::ExamplesPassed),
+			answer: since(Event::Answered),
+		});
+	}
+
+	if years.is_empty() {
+		reporter.warning("No scaffolded days in the journal");
+		return Ok(());
+	}
+
+	for (year, solves) in years {
+		reporter.heading(&year.to_string());
+		for solve in &solves {
+			let examples = match solve.examples {
+				Some(d) => format!("examples {}", format_duration(d)),
+				None => t.label("examples not passed"),
+			};
+			let answer = match solve.answer {
+				Some(d) => {
+					let mut times = solves
+						.iter()
+						.filter(|s| s.challenge.part == solve.challenge.part)
+						.filter_map(|s| s.answer)
+						.collect::<Vec<Duration>>();
+					times.sort();
+					let rank = times.iter().position(|&other| other == d).unwrap() + 1;
+					format!(
+						"{} {}",
+						t.pass(&format!("answered {}", format_duration(d))),
+						t.label(&format!("#{} of {}", rank, times.len()))
+					)
+				}
+				None => t.label("not answered"),
+			};
+			println!(
+				"{} - {}, {}",
+				t.label(&solve.challenge.label()),
+				examples,
+				answer
+			);
+		}
+	}
+
+	Ok(())
+}
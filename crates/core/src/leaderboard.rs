@@ -0,0 +1,169 @@
+//! Private leaderboards, from the site's JSON API or a saved copy of it.
+//!
+//! Downloads are cached in [`CACHE_DIR`] and reused for [`CACHE_TTL`], as
+//! the site asks not to fetch a leaderboard more often than that.
+
+use std::{
+	collections::HashMap,
+	path::{Path, PathBuf},
+	time::{Duration, SystemTime},
+};
+
+use anyhow::anyhow;
+use serde::Deserialize;
+
+use crate::{fetch::Client, report::Theme, unlock};
+
+pub const CACHE_DIR: &str = "./.aoc/leaderboards";
+pub const CACHE_TTL: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Deserialize, Debug)]
+pub struct Leaderboard {
+	pub event: String,
+	pub owner_id: u64,
+	pub members: HashMap<String, Member>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Member {
+	pub id: u64,
+	/// Missing for anonymous users.
+	pub name: Option<String>,
+	pub stars: usize,
+	pub local_score: u64,
+	/// Seconds since the unix epoch, or 0 without any stars.
+	pub last_star_ts: u64,
+	/// When each star was got, by day then part.
+	#[serde(default)]
+	pub completion_day_level: HashMap<String, HashMap<String, Star>>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Star {
+	pub get_star_ts: u64,
+}
+
+impl Member {
+	pub fn name(&self) -> String {
+		match &self.name {
+			Some(name) => name.clone(),
+			None => format!("(anonymous user #{})", self.id),
+		}
+	}
+
+	/// How many stars of a day the member has.
+	pub fn stars_on(&self, day: usize) -> usize {
+		self
+			.completion_day_level
+			.get(&day.to_string())
+			.map(|parts| parts.len())
+			.unwrap_or(0)
+	}
+}
+
+impl Leaderboard {
+	pub fn parse(json: &str) -> Result<Self, anyhow::Error> {
+		serde_json::from_str(json)
+			.map_err(|err| anyhow!("Invalid leaderboard JSON: {}", err))
+	}
+
+	/// Members by local score, ties going to who got their last star first.
+	pub fn ranked(&self) -> Vec<&Member> {
+		let mut members = self.members.values().collect::<Vec<&Member>>();
+		members.sort_by_key(|m| {
+			(std::cmp::Reverse(m.local_score), m.last_star_ts, m.id)
+		});
+		members
+	}
+}
+
+pub fn cache_path(year: usize, id: u64) -> PathBuf {
+	PathBuf::from(format!("{}/{}-{}.json", CACHE_DIR, year, id))
+}
+
+fn read(path: &Path) -> Result<String, anyhow::Error> {
+	std::fs::read_to_string(path)
+		.map_err(|err| anyhow!("Error reading {}: {}", path.display(), err))
+}
+
+/// The JSON of a leaderboard, from the cache if it's fresh enough and
+/// downloaded into it otherwise.
+pub fn fetch(
+	client: &Client,
+	year: usize,
+	id: u64,
+) -> Result<String, anyhow::Error> {
+	let path = cache_path(year, id);
+	let age = std::fs::metadata(&path)
+		.and_then(|meta| meta.modified())
+		.ok()
+		.and_then(|modified| SystemTime::now().duration_since(modified).ok());
+	if age.is_some_and(|age| age < CACHE_TTL) {
+		return read(&path);
+	}
+
+	let json = client.leaderboard(year, id)?;
+	// don't cache an error page
+	Leaderboard::parse(&json)?;
+	std::fs::create_dir_all(CACHE_DIR)
+		.map_err(|err| anyhow!("Error creating {}: {}", CACHE_DIR, err))?;
+	std::fs::write(&path, &json)
+		.map_err(|err| anyhow!("Error writing {}: {}", path.display(), err))?;
+	Ok(json)
+}
+
+/// A saved copy of a leaderboard's JSON.
+pub fn load(path: &Path) -> Result<String, anyhow::Error> {
+	read(path)
+}
+
+/// The leaderboard as a table of members with their rank, local score and
+/// stars of each day, like the site shows it.
+pub fn render(board: &Leaderboard, theme: Theme) -> String {
+	let t = theme;
+	// the event is its year
+	let days = board.event.parse::<usize>().map_or(25, unlock::days_in);
+	let members = board.ranked();
+	let rank_width = members.len().to_string().len() + 1;
+	let score_width = members
+		.iter()
+		.map(|m| m.local_score.to_string().len())
+		.max()
+		.unwrap_or(1);
+	let indent = " ".repeat(rank_width + score_width + 2);
+
+	let mut res = String::new();
+	let tens = (1..=days)
+		.map(|day| {
+			if day < 10 {
+				' '
+			} else {
+				char::from(b'0' + (day / 10) as u8)
+			}
+		})
+		.collect::<String>();
+	let ones = (1..=days)
+		.map(|day| char::from(b'0' + (day % 10) as u8))
+		.collect::<String>();
+	res.push_str(&format!("{}{}\n", indent, t.label(&tens)));
+	res.push_str(&format!("{}{}\n", indent, t.label(&ones)));
+
+	for (i, member) in members.iter().enumerate() {
+		let stars = (1..=days)
+			.map(|day| match member.stars_on(day) {
+				0 => t.label("·"),
+				1 => "☆".to_string(),
+				_ => t.warn("★"),
+			})
+			.collect::<String>();
+		res.push_str(&format!(
+			"{:>rank_width$} {:>score_width$} {} {} {}\n",
+			format!("{})", i + 1),
+			member.local_score,
+			stars,
+			member.name(),
+			t.label(&format!("({} ★)", member.stars)),
+		));
+	}
+	res
+}
@@ -0,0 +1,31 @@
+//! The challenge registry every year's solutions submit themselves to, and
+//! the runner that tests and times them.
+
+#[macro_use]
+pub mod capture;
+pub mod baseline;
+pub mod budget;
+pub mod calendar;
+pub mod common;
+pub mod crypt;
+pub mod embedded;
+pub mod fetch;
+pub mod history;
+pub mod journal;
+pub mod leaderboard;
+pub mod list;
+pub mod memory;
+pub mod notes;
+pub mod progress;
+pub mod puzzle;
+pub mod readme;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod store;
+pub mod stored;
+pub mod summary;
+pub mod unlock;
+pub mod visual;
+
+pub use common::{all_challenges, Challenge, ChallengeFn, ChallengeResult};
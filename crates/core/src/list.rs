@@ -0,0 +1,172 @@
+//! What's registered, and what we have on disk for it.
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::{common::Challenge, notes, report::Reporter, store, unlock};
+
+/// Stars available in the event of `year`: two parts of each day.
+pub fn stars_in(year: usize) -> usize {
+	2 * unlock::days_in(year)
+}
+
+#[derive(Serialize)]
+pub struct Listing {
+	pub year: usize,
+	pub day: usize,
+	pub part: usize,
+	pub skip: bool,
+	pub examples: usize,
+	/// Whether there's an input, embedded or on disk.
+	pub input: bool,
+	/// The known answer, if one is stored.
+	pub answer: Option<u128>,
+	/// The notes of the challenge's day, if it has any.
+	pub notes: Option<String>,
+}
+
+impl Listing {
+	pub fn new(challenge: &Challenge) -> Result<Self, anyhow::Error> {
+		Ok(Self {
+			year: challenge.year,
+			day: challenge.day,
+			part: challenge.part,
+			skip: challenge.skip,
+			examples: challenge.unit_tests.len(),
+			input: store::has_input(challenge.year, challenge.day),
+			answer: store::known_answer(challenge)?,
+			notes: notes::read(challenge.year, challenge.day)?,
+		})
+	}
+}
+
+#[derive(Serialize, Default)]
+pub struct YearStatus {
+	pub year: usize,
+	/// Parts with a known answer.
+	pub stars: usize,
+	pub challenges: usize,
+	pub skipped: usize,
+	pub missing_inputs: usize,
+}
+
+pub fn listings(
+	challenges: &[&'static Challenge],
+) -> Result<Vec<Listing>, anyhow::Error> {
+	challenges.iter().map(|c| Listing::new(c)).collect()
+}
+
+pub fn statuses(listings: &[Listing]) -> Vec<YearStatus> {
+	let mut years = BTreeMap::<usize, YearStatus>::new();
+	for listing in listings {
+		let status = years.entry(listing.year).or_insert_with(|| YearStatus {
+			year: listing.year,
+			..Default::default()
+		});
+		status.challenges += 1;
+		if listing.answer.is_some() {
+			status.stars += 1;
+		}
+		if listing.skip {
+			status.skipped += 1;
+		}
+		if !listing.input {
+			status.missing_inputs += 1;
+		}
+	}
+	years.into_values().collect()
+}
+
+/// Prints every challenge with what's known about it, and the first line of
+/// its day's notes.
+pub fn list(
+	challenges: &[&'static Challenge],
+	reporter: &dyn Reporter,
+	json: bool,
+) -> Result<(), anyhow::Error> {
+	let listings = listings(challenges)?;
+	if json {
+		println!("{}", serde_json::to_string_pretty(&listings)?);
+		return Ok(());
+	}
+
+	let t = reporter.theme();
+	let mut previous_day = None;
+	for (challenge, listing) in challenges.iter().zip(&listings) {
+		let mut line = format!(
+			"{} - {} example{}",
+			t.label(&challenge.label()),
+			listing.examples,
+			if listing.examples == 1 { "" } else { "s" }
+		);
+		if listing.input {
+			line.push_str(", input");
+		} else {
+			line.push_str(&format!(", {}", t.fail("no input")));
+		}
+		match listing.answer {
+			Some(answer) => {
+				line.push_str(&format!(", answer {}", t.pass(&answer.to_string())))
+			}
+			None => line.push_str(&format!(", {}", t.label("no answer"))),
+		}
+		if listing.skip {
+			line.push_str(&format!(" {}", t.warn("(skipped)")));
+		}
+		println!("{}", line);
+
+		// the notes are per day, so only under its first part
+		let day = Some((listing.year, listing.day));
+		if previous_day != day {
+			if let Some(summary) = listing.notes.as_deref().and_then(notes::summary) {
+				println!("  {}", t.label(summary));
+			}
+		}
+		previous_day = day;
+	}
+
+	Ok(())
+}
+
+/// Prints how many stars each year has, counting a star for every part with a
+/// known answer.
+pub fn status(
+	challenges: &[&'static Challenge],
+	reporter: &dyn Reporter,
+	json: bool,
+) -> Result<(), anyhow::Error> {
+	let statuses = statuses(&listings(challenges)?);
+	if json {
+		println!("{}", serde_json::to_string_pretty(&statuses)?);
+		return Ok(());
+	}
+
+	let t = reporter.theme();
+	for status in &statuses {
+		let total = stars_in(status.year);
+		let stars = format!("{:>2}/{} ★", status.stars, total);
+		let stars = if status.stars == total {
+			t.pass(&stars)
+		} else {
+			t.warn(&stars)
+		};
+		let mut details = vec![format!("{} challenges", status.challenges)];
+		if status.skipped > 0 {
+			details.push(format!("{} skipped", status.skipped));
+		}
+		if status.missing_inputs > 0 {
+			details.push(format!("{} without input", status.missing_inputs));
+		}
+		println!(
+			"{} - {} {}",
+			t.label(&status.year.to_string()),
+			stars,
+			t.label(&format!("({})", details.join(", ")))
+		);
+	}
+	let total: usize = statuses.iter().map(|s| s.stars).sum();
+	reporter.detail(&format!("{} stars in total", total));
+
+	Ok(())
+}
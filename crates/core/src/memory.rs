@@ -0,0 +1,158 @@
+//! Heap usage of challenge runs, with the `memory` feature.
+//!
+//! The feature installs a global allocator that counts allocations per
+//! thread. Only allocations made on the thread running the challenge are
+//! counted, so work a solution hands to other threads (e.g. through rayon)
+//! doesn't show up.
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct MemoryStats {
+	/// The most memory allocated at once, in bytes.
+	pub peak: u64,
+	/// Every byte ever allocated, in bytes.
+	pub total: u64,
+	/// The number of allocations.
+	pub count: u64,
+}
+
+impl MemoryStats {
+	pub fn summary(&self) -> String {
+		format!(
+			"peak {}, total {}, {} allocs",
+			format_bytes(self.peak),
+			format_bytes(self.total),
+			self.count
+		)
+	}
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+	const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+	let mut value = bytes as f64;
+	let mut unit = 0;
+	while value >= 1024.0 && unit < UNITS.len() - 1 {
+		value /= 1024.0;
+		unit += 1;
+	}
+	if unit == 0 {
+		format!("{} {}", bytes, UNITS[0])
+	} else {
+		format!("{:.1} {}", value, UNITS[unit])
+	}
+}
+
+/// Runs `f`, returning its result along with the heap usage of this thread
+/// while it ran. The usage is only available with the `memory` feature.
+#[cfg(feature = "memory")]
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<MemoryStats>) {
+	let outer = counting::reset();
+	let res = f();
+	let stats = counting::restore(outer);
+	(res, Some(stats))
+}
+
+#[cfg(not(feature = "memory"))]
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<MemoryStats>) {
+	(f(), None)
+}
+
+#[cfg(feature = "memory")]
+mod counting {
+	use std::{
+		alloc::{GlobalAlloc, Layout, System},
+		cell::Cell,
+	};
+
+	use super::MemoryStats;
+
+	#[derive(Clone, Copy)]
+	pub struct Counters {
+		/// Can go negative when memory allocated before measuring is freed.
+		current: i64,
+		peak: i64,
+		total: u64,
+		count: u64,
+	}
+
+	const ZERO: Counters = Counters {
+		current: 0,
+		peak: 0,
+		total: 0,
+		count: 0,
+	};
+
+	thread_local! {
+		static COUNTERS: Cell<Counters> = const { Cell::new(ZERO) };
+	}
+
+	fn update(f: impl FnOnce(&mut Counters)) {
+		// the thread local may already be gone while a thread shuts down
+		let _ = COUNTERS.try_with(|c| {
+			let mut counters = c.get();
+			f(&mut counters);
+			counters.peak = counters.peak.max(counters.current);
+			c.set(counters);
+		});
+	}
+
+	pub fn reset() -> Counters {
+		COUNTERS.with(|c| c.replace(ZERO))
+	}
+
+	pub fn restore(outer: Counters) -> MemoryStats {
+		let counters = COUNTERS.with(|c| c.replace(outer));
+		MemoryStats {
+			peak: counters.peak.max(0) as u64,
+			total: counters.total,
+			count: counters.count,
+		}
+	}
+
+	struct Counting;
+
+	#[global_allocator]
+	static ALLOCATOR: Counting = Counting;
+
+	unsafe impl GlobalAlloc for Counting {
+		unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+			let size = layout.size();
+			update(|c| {
+				c.current += size as i64;
+				c.total += size as u64;
+				c.count += 1;
+			});
+			unsafe { System.alloc(layout) }
+		}
+
+		unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+			let size = layout.size();
+			update(|c| {
+				c.current += size as i64;
+				c.total += size as u64;
+				c.count += 1;
+			});
+			unsafe { System.alloc_zeroed(layout) }
+		}
+
+		unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+			let size = layout.size();
+			update(|c| c.current -= size as i64);
+			unsafe { System.dealloc(ptr, layout) }
+		}
+
+		unsafe fn realloc(
+			&self,
+			ptr: *mut u8,
+			layout: Layout,
+			new_size: usize,
+		) -> *mut u8 {
+			let old_size = layout.size();
+			update(|c| {
+				c.current += new_size as i64 - old_size as i64;
+				c.total += new_size.saturating_sub(old_size) as u64;
+				c.count += 1;
+			});
+			unsafe { System.realloc(ptr, layout, new_size) }
+		}
+	}
+}
@@ -0,0 +1,33 @@
+//! Notes on each day's solution: its approach, complexity and gotchas.
+//!
+//! They're markdown files next to the day's module, beside its archived
+//! puzzle description, and are shown by the `list` and `show` commands.
+
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+
+use crate::scaffold;
+
+pub fn notes_path(year: usize, day: usize) -> PathBuf {
+	scaffold::crate_dir(year).join(format!("src/day{:0>2}.notes.md", day))
+}
+
+/// The notes of a day, if it has any.
+pub fn read(year: usize, day: usize) -> Result<Option<String>, anyhow::Error> {
+	let path = notes_path(year, day);
+	match std::fs::read_to_string(&path) {
+		Ok(notes) if notes.trim().is_empty() => Ok(None),
+		Ok(notes) => Ok(Some(notes)),
+		Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+		Err(err) => Err(anyhow!("Error reading {}: {}", path.display(), err)),
+	}
+}
+
+/// The first line of text of some notes, skipping headings.
+pub fn summary(notes: &str) -> Option<&str> {
+	notes
+		.lines()
+		.map(|line| line.trim())
+		.find(|line| !line.is_empty() && !line.starts_with('#'))
+}
@@ -0,0 +1,163 @@
+//! Live status of the challenges currently running on their real input.
+//!
+//! On a terminal every running challenge gets its own line with its elapsed
+//! time, which turns into a progress bar once the solution calls [`report`].
+//! Otherwise a plain line is printed when each challenge starts and finishes.
+
+use std::{
+	cell::RefCell,
+	io::IsTerminal,
+	time::{Duration, Instant},
+};
+
+use indicatif::{
+	MultiProgress, ProgressBar, ProgressDrawTarget, ProgressStyle,
+};
+
+use crate::report::Theme;
+
+thread_local! {
+	static CURRENT: RefCell<Option<Handle>> = const { RefCell::new(None) };
+}
+
+/// Reports that the challenge running on this thread has done `done` out of
+/// `total` units of work. Does nothing when there's nothing to report to.
+pub fn report(done: u64, total: u64) {
+	if let Some(handle) = current() {
+		handle.report(done, total);
+	}
+}
+
+/// A handle to the progress line of the challenge running on this thread,
+/// for reporting from other threads the solution spawns.
+pub fn current() -> Option<Handle> {
+	CURRENT.with(|c| c.borrow().clone())
+}
+
+/// Where a single challenge reports its progress.
+#[derive(Clone)]
+pub struct Handle {
+	label: String,
+	bar: Option<ProgressBar>,
+}
+
+impl Handle {
+	/// The label of the task this reports to.
+	pub fn label(&self) -> &str {
+		&self.label
+	}
+
+	/// Makes this the current handle of the calling thread, for solutions
+	/// run on a thread of their own.
+	pub fn attach(self) {
+		CURRENT.with(|c| *c.borrow_mut() = Some(self));
+	}
+
+	pub fn report(&self, done: u64, total: u64) {
+		if let Some(bar) = &self.bar {
+			if bar.length() != Some(total) {
+				bar.set_style(bar_style());
+				bar.set_length(total);
+			}
+			bar.set_position(done);
+		}
+	}
+}
+
+enum Mode {
+	Live(MultiProgress),
+	Plain(Theme),
+	Hidden,
+}
+
+pub struct Progress {
+	mode: Mode,
+}
+
+impl Progress {
+	/// Live lines when stdout is a terminal, plain lines in `theme`
+	/// otherwise.
+	pub fn auto(theme: Theme) -> Self {
+		let mode = if std::io::stdout().is_terminal() {
+			Mode::Live(MultiProgress::with_draw_target(ProgressDrawTarget::stdout()))
+		} else {
+			Mode::Plain(theme)
+		};
+		Self { mode }
+	}
+
+	/// Shows nothing at all.
+	pub fn hidden() -> Self {
+		Self { mode: Mode::Hidden }
+	}
+
+	/// Starts a line for `label`, making it the current one for this thread
+	/// until the returned task is dropped.
+	pub fn start(&self, label: String) -> Task {
+		let bar = match &self.mode {
+			Mode::Live(multi) => {
+				let bar = multi.add(ProgressBar::new_spinner());
+				bar.set_style(spinner_style());
+				bar.set_prefix(label.clone());
+				bar.enable_steady_tick(Duration::from_millis(100));
+				Some(bar)
+			}
+			Mode::Plain(theme) => {
+				println!("{} - started", theme.label(&label));
+				None
+			}
+			Mode::Hidden => None,
+		};
+		let handle = Handle { label, bar };
+		let outer = CURRENT.with(|c| c.replace(Some(handle.clone())));
+		Task {
+			plain: match self.mode {
+				Mode::Plain(theme) => Some(theme),
+				Mode::Live(_) | Mode::Hidden => None,
+			},
+			handle,
+			outer,
+			start: Instant::now(),
+		}
+	}
+}
+
+pub struct Task {
+	/// How to print the finished line, if not live.
+	plain: Option<Theme>,
+	handle: Handle,
+	/// The task that was current when this one started, which can still be
+	/// running when rayon runs this one inside it.
+	outer: Option<Handle>,
+	start: Instant,
+}
+
+impl Drop for Task {
+	fn drop(&mut self) {
+		let outer = self.outer.take();
+		CURRENT.with(|c| *c.borrow_mut() = outer);
+		if let Some(bar) = &self.handle.bar {
+			bar.finish_and_clear();
+		}
+		if let Some(theme) = self.plain {
+			println!(
+				"{} - finished {}",
+				theme.label(&self.handle.label),
+				theme.label(&format!("({}s)", self.start.elapsed().as_secs_f64()))
+			);
+		}
+	}
+}
+
+fn spinner_style() -> ProgressStyle {
+	ProgressStyle::with_template("{spinner} {prefix} - {elapsed_precise}")
+		.unwrap()
+}
+
+fn bar_style() -> ProgressStyle {
+	ProgressStyle::with_template(
+		"{spinner} {prefix} - {elapsed_precise} [{bar:30}] {percent}%",
+	)
+	.unwrap()
+	.progress_chars("=> ")
+}
@@ -0,0 +1,323 @@
+//! An offline archive of puzzle descriptions.
+//!
+//! Saved puzzle pages (see [`store::puzzle_path`]) are converted to markdown
+//! next to their day's module, so they're versioned along with it and can be
+//! reread in the terminal.
+
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+
+use crate::{
+	notes,
+	report::{Reporter, Theme},
+	scaffold, store,
+};
+
+pub fn markdown_path(year: usize, day: usize) -> PathBuf {
+	scaffold::crate_dir(year).join(format!("src/day{:0>2}.md", day))
+}
+
+fn decode_entities(text: &str) -> String {
+	text
+		.replace("&lt;", "<")
+		.replace("&gt;", ">")
+		.replace("&quot;", "\"")
+		.replace("&#39;", "'")
+		.replace("&apos;", "'")
+		.replace("&nbsp;", " ")
+		.replace("&amp;", "&")
+}
+
+/// Escapes the characters [`render`] reads as markup.
+fn escape(text: &str) -> String {
+	let mut res = String::new();
+	for c in text.chars() {
+		if matches!(c, '\\' | '`' | '*' | '[') {
+			res.push('\\');
+		}
+		res.push(c);
+	}
+	res
+}
+
+/// The value of an attribute in the inside of a tag, like `a href="/2023"`.
+fn attribute(tag: &str, name: &str) -> Option<String> {
+	let start = tag.find(&format!("{}=\"", name))? + name.len() + 2;
+	let len = tag[start..].find('"')?;
+	Some(decode_entities(&tag[start..start + len]))
+}
+
+/// Converts the puzzle descriptions of a puzzle page, its `<article>`s, to
+/// markdown. Only handles the handful of tags the site uses in them.
+pub fn html_to_markdown(html: &str) -> String {
+	let mut res = String::new();
+	let mut in_article = false;
+	let mut in_pre = false;
+	let mut in_code = false;
+	let mut links = Vec::<String>::new();
+
+	let mut rest = html;
+	while !rest.is_empty() {
+		let (text, tag) = match rest.find('<') {
+			Some(0) => match rest.find('>') {
+				Some(end) => {
+					let tag = &rest[1..end];
+					rest = &rest[end + 1..];
+					(None, Some(tag))
+				}
+				// a truncated page, keep what's left as text
+				None => {
+					let text = rest;
+					rest = "";
+					(Some(text), None)
+				}
+			},
+			Some(start) => {
+				let text = &rest[..start];
+				rest = &rest[start..];
+				(Some(text), None)
+			}
+			None => {
+				let text = rest;
+				rest = "";
+				(Some(text), None)
+			}
+		};
+
+		if let Some(text) = text {
+			if !in_article {
+				continue;
+			}
+			let text = decode_entities(text);
+			// code is taken literally, anything else could be mistaken for markup
+			let text = if in_pre || in_code {
+				text
+			} else {
+				escape(&text)
+			};
+			if in_pre {
+				res.push_str(&text);
+			} else {
+				let collapsed =
+					text.split_whitespace().collect::<Vec<&str>>().join(" ");
+				if text.starts_with(char::is_whitespace) && !res.ends_with([' ', '\n'])
+				{
+					res.push(' ');
+				}
+				res.push_str(&collapsed);
+				if text.ends_with(char::is_whitespace) && !collapsed.is_empty() {
+					res.push(' ');
+				}
+			}
+			continue;
+		}
+
+		let Some(tag) = tag else { continue };
+		let closing = tag.starts_with('/');
+		let name = tag
+			.trim_start_matches('/')
+			.split(|c: char| c.is_whitespace() || c == '/')
+			.next()
+			.unwrap_or("");
+		if name == "article" {
+			in_article = !closing;
+			continue;
+		}
+		if !in_article {
+			continue;
+		}
+		match (name, closing) {
+			("h2", false) => res.push_str("## "),
+			("h2", true) | ("p", true) | ("ul", true) => {
+				res.truncate(res.trim_end_matches(' ').len());
+				res.push_str("\n\n")
+			}
+			("pre", false) => {
+				in_pre = true;
+				res.push_str("```\n");
+			}
+			("pre", true) => {
+				in_pre = false;
+				if !res.ends_with('\n') {
+					res.push('\n');
+				}
+				res.push_str("```\n\n");
+			}
+			("code", _) if !in_pre => {
+				in_code = !closing;
+				res.push('`');
+			}
+			("em", _) if !in_pre && !in_code => res.push('*'),
+			("li", false) => res.push_str("- "),
+			("li", true) => {
+				res.truncate(res.trim_end_matches(' ').len());
+				res.push('\n')
+			}
+			("br", _) => res.push('\n'),
+			("a", false) => {
+				links.push(attribute(tag, "href").unwrap_or_default());
+				res.push('[');
+			}
+			("a", true) => {
+				res.push_str(&format!("]({})", links.pop().unwrap_or_default()))
+			}
+			_ => {}
+		}
+	}
+
+	let mut res = res
+		.lines()
+		.map(|line| line.trim_end())
+		.collect::<Vec<&str>>()
+		.join("\n");
+	while res.contains("\n\n\n") {
+		res = res.replace("\n\n\n", "\n\n");
+	}
+	format!("{}\n", res.trim())
+}
+
+/// Converts the saved page of a day to markdown if it's newer than the
+/// markdown, returning the markdown's path if it was written.
+pub fn archive(
+	year: usize,
+	day: usize,
+) -> Result<Option<PathBuf>, anyhow::Error> {
+	let html_path = store::puzzle_path(year, day);
+	let md_path = markdown_path(year, day);
+	let modified = |path: &PathBuf| {
+		std::fs::metadata(path)
+			.and_then(|meta| meta.modified())
+			.ok()
+	};
+	let Some(html_modified) = modified(&html_path) else {
+		return Ok(None);
+	};
+	if modified(&md_path).is_some_and(|md| md >= html_modified) {
+		return Ok(None);
+	}
+	if !scaffold::crate_dir(year).exists() {
+		return Ok(None);
+	}
+
+	let html = std::fs::read_to_string(&html_path)
+		.map_err(|err| anyhow!("Error reading {}: {}", html_path.display(), err))?;
+	let markdown = html_to_markdown(&html);
+	if markdown.trim().is_empty() {
+		return Err(anyhow!("No puzzle description in {}", html_path.display()));
+	}
+	std::fs::write(&md_path, markdown)
+		.map_err(|err| anyhow!("Error writing {}: {}", md_path.display(), err))?;
+	Ok(Some(md_path))
+}
+
+/// The first `c` in `text` that isn't escaped.
+fn find_unescaped(text: &str, c: char) -> Option<usize> {
+	let mut escaped = false;
+	for (i, next) in text.char_indices() {
+		match next {
+			_ if escaped => escaped = false,
+			'\\' => escaped = true,
+			_ if next == c => return Some(i),
+			_ => {}
+		}
+	}
+	None
+}
+
+/// Styles the inline markup of a line: code, emphasis and links.
+fn render_inline(line: &str, t: Theme) -> String {
+	let mut res = String::new();
+	let mut rest = line;
+	while let Some(start) = rest.find(['\\', '`', '*', '[']) {
+		res.push_str(&rest[..start]);
+		let marker = &rest[start..start + 1];
+		let after = &rest[start + 1..];
+		let styled = match marker {
+			// an escaped character is taken literally
+			"\\" => after.chars().next().map(|c| (c.to_string(), c.len_utf8())),
+			"[" => after.find("](").and_then(|mid| {
+				let end = after[mid..].find(')')? + mid;
+				Some((render_inline(&after[..mid], t), end + 1))
+			}),
+			"`" => after.find('`').map(|end| (t.code(&after[..end]), end + 1)),
+			_ => find_unescaped(after, '*').map(|end| {
+				(
+					t.emphasis(&render_inline(&after[..end], Theme::plain())),
+					end + 1,
+				)
+			}),
+		};
+		match styled {
+			Some((text, len)) => {
+				res.push_str(&text);
+				rest = &after[len..];
+			}
+			None => {
+				res.push_str(marker);
+				rest = after;
+			}
+		}
+	}
+	res.push_str(rest);
+	res
+}
+
+/// Renders archived markdown, or notes, for the terminal.
+pub fn render(markdown: &str, theme: Theme) -> String {
+	let t = theme;
+	let mut res = String::new();
+	let mut in_block = false;
+	for line in markdown.lines() {
+		if line.starts_with("```") {
+			in_block = !in_block;
+			continue;
+		}
+		let line = if in_block {
+			format!("    {}", t.code(line))
+		} else if line.starts_with('#') {
+			t.emphasis(line.trim_start_matches('#').trim_start())
+		} else {
+			render_inline(line, t)
+		};
+		res.push_str(&line);
+		res.push('\n');
+	}
+	res
+}
+
+/// Prints the archived description of a day, archiving it first if there's
+/// a newer saved page, followed by the day's notes.
+pub fn show(
+	year: usize,
+	day: usize,
+	reporter: &dyn Reporter,
+) -> Result<(), anyhow::Error> {
+	archive(year, day)?;
+	let path = markdown_path(year, day);
+	let markdown = match std::fs::read_to_string(&path) {
+		Ok(markdown) => Some(markdown),
+		Err(err) if err.kind() == std::io::ErrorKind::NotFound => None,
+		Err(err) => {
+			return Err(anyhow!("Error reading {}: {}", path.display(), err))
+		}
+	};
+	let notes = notes::read(year, day)?;
+	if markdown.is_none() && notes.is_none() {
+		return Err(anyhow!(
+			"No description of {} day {}, save the puzzle page to {} first",
+			year,
+			day,
+			store::puzzle_path(year, day).display()
+		));
+	}
+
+	if let Some(markdown) = markdown {
+		print!("{}", render(&markdown, reporter.theme()));
+	}
+	if let Some(notes) = notes {
+		reporter.heading("Notes");
+		print!("{}", render(&notes, reporter.theme()));
+	}
+	Ok(())
+}
@@ -0,0 +1,127 @@
+//! Regenerating the progress section of the README.
+//!
+//! Everything between the [`START`] and [`END`] markers is replaced with the
+//! stars of each year and a table of median runtimes from the history, so
+//! the rest of the file can be edited by hand.
+
+use std::{path::Path, time::Duration};
+
+use anyhow::anyhow;
+
+use crate::{
+	common::Challenge,
+	history,
+	list::{self, stars_in},
+};
+
+pub const START: &str = "<!-- progress:start -->";
+pub const END: &str = "<!-- progress:end -->";
+
+fn format_duration(duration: Duration) -> String {
+	let secs = duration.as_secs_f64();
+	if secs >= 1.0 {
+		format!("{:.2}s", secs)
+	} else if secs >= 1e-3 {
+		format!("{:.2}ms", secs * 1e3)
+	} else {
+		format!("{:.2}µs", secs * 1e6)
+	}
+}
+
+fn median(mut durations: Vec<Duration>) -> Option<Duration> {
+	durations.sort();
+	let mid = durations.len() / 2;
+	match durations.len() {
+		0 => None,
+		n if n.is_multiple_of(2) => Some((durations[mid - 1] + durations[mid]) / 2),
+		_ => Some(durations[mid]),
+	}
+}
+
+/// The generated section, without the markers. `years` are listed even if
+/// they have no challenges yet; runtimes are the median of each challenge's
+/// last `last` runs on this machine.
+pub fn render(
+	challenges: &[&'static Challenge],
+	years: &[usize],
+	last: usize,
+) -> Result<String, anyhow::Error> {
+	let statuses = list::statuses(&list::listings(challenges)?);
+	let entries = history::load()?;
+	let machine = history::machine_id();
+
+	let mut res = String::new();
+	for &year in years {
+		let stars = statuses
+			.iter()
+			.find(|s| s.year == year)
+			.map(|s| s.stars)
+			.unwrap_or(0);
+		let total = stars_in(year);
+		res.push_str(&format!(
+			"- [{}] {} - {}/{} ★\n",
+			if stars == total { "x" } else { " " },
+			year,
+			stars,
+			total
+		));
+	}
+
+	res.push_str("\n| Year | Day | Part | Median runtime |\n");
+	res.push_str("| ---- | --- | ---- | -------------: |\n");
+	for challenge in challenges {
+		let runs = history::timings(&entries, challenge, &machine);
+		let recent = &runs[runs.len().saturating_sub(last.max(1))..];
+		let runtime = if challenge.skip {
+			"skipped".to_string()
+		} else {
+			median(recent.iter().map(|e| e.duration()).collect())
+				.map(format_duration)
+				.unwrap_or_else(|| "-".to_string())
+		};
+		res.push_str(&format!(
+			"| {} | {} | {} | {} |\n",
+			challenge.year, challenge.day, challenge.part, runtime
+		));
+	}
+
+	Ok(res)
+}
+
+/// Replaces the marked section of `readme` with `section`, which should end
+/// with a newline.
+pub fn splice(readme: &str, section: &str) -> Result<String, anyhow::Error> {
+	let start = readme
+		.find(START)
+		.ok_or_else(|| anyhow!("Missing {} marker", START))?;
+	let end = readme[start..]
+		.find(END)
+		.map(|end| start + end)
+		.ok_or_else(|| anyhow!("Missing {} marker after {}", END, START))?;
+	Ok(format!(
+		"{}\n{}{}",
+		&readme[..start + START.len()],
+		section,
+		&readme[end..]
+	))
+}
+
+/// Regenerates the marked section of the README at `path`, returning whether
+/// it changed.
+pub fn update(
+	path: &Path,
+	challenges: &[&'static Challenge],
+	years: &[usize],
+	last: usize,
+) -> Result<bool, anyhow::Error> {
+	let readme = std::fs::read_to_string(path)
+		.map_err(|err| anyhow!("Error reading {}: {}", path.display(), err))?;
+	let updated = splice(&readme, &render(challenges, years, last)?)
+		.map_err(|err| anyhow!("{} in {}", err, path.display()))?;
+	if updated == readme {
+		return Ok(false);
+	}
+	std::fs::write(path, updated)
+		.map_err(|err| anyhow!("Error writing {}: {}", path.display(), err))?;
+	Ok(true)
+}
@@ -0,0 +1,134 @@
+use crate::{
+	common::{Challenge, RunResult, TestResults, UnitTestResult},
+	memory::format_bytes,
+	summary::Summary,
+};
+
+use super::{Reporter, Theme};
+
+/// One line per challenge, without failing inputs.
+pub struct Compact {
+	theme: Theme,
+}
+
+impl Compact {
+	pub fn new(theme: Theme) -> Self {
+		Self { theme }
+	}
+}
+
+fn short_label(challenge: &Challenge) -> String {
+	format!(
+		"{}-{:0>2}-{}",
+		challenge.year, challenge.day, challenge.part
+	)
+}
+
+impl Reporter for Compact {
+	fn theme(&self) -> Theme {
+		self.theme
+	}
+
+	fn run_error(&self, challenge: &Challenge, err: &anyhow::Error) {
+		let t = self.theme;
+		println!(
+			"{} {}",
+			t.label(&short_label(challenge)),
+			t.fail(&err.to_string())
+		);
+	}
+
+	fn blocked(&self, challenge: &Challenge) {
+		let t = self.theme;
+		println!("{} {}", t.label(&short_label(challenge)), t.warn("blocked"));
+	}
+
+	fn summary_line(&self, summary: &Summary) {
+		self.summary(summary);
+	}
+
+	fn summary(&self, summary: &Summary) {
+		let t = self.theme;
+		let text = format!(
+			"tests {}/{} runs {}/{} matched {} errors {} timeouts {} wrong {} \
+			 blocked {}",
+			summary.passed_examples(),
+			summary.tested,
+			summary.ran - summary.run_errors - summary.timeouts,
+			summary.ran,
+			summary.matched,
+			summary.run_errors,
+			summary.timeouts,
+			summary.wrong_answers,
+			summary.blocked,
+		);
+		let text = if summary.failed() {
+			t.fail(&text)
+		} else {
+			t.pass(&text)
+		};
+		println!("{} {}", t.label("=="), text);
+	}
+
+	fn heading(&self, text: &str) {
+		println!("{}", self.theme.label(&format!("== {}", text)));
+	}
+
+	fn unit_tests(&self, results: &TestResults) {
+		let t = self.theme;
+		let label = t.label(&short_label(results.challenge));
+
+		if results.challenge.skip {
+			println!("{} {}", label, t.label("skip"));
+			return;
+		}
+
+		let counts = format!("{}/{}", results.n_passed(), results.results.len());
+		if results.passed() {
+			println!("{} {}", label, t.pass(&counts));
+			return;
+		}
+
+		let failures = results
+			.results
+			.iter()
+			.enumerate()
+			.filter_map(|(i, res)| match res {
+				UnitTestResult::Equal => None,
+				UnitTestResult::NotEqual { expected, got, .. } => {
+					Some(format!("#{} expected {} got {}", i, expected, got))
+				}
+				UnitTestResult::Other(err) => Some(format!("#{} {}", i, err)),
+			})
+			.collect::<Vec<String>>()
+			.join("; ");
+		println!("{} {} {}", label, t.fail(&counts), t.fail(&failures));
+	}
+
+	fn run(&self, result: &RunResult) {
+		let t = self.theme;
+		let label = t.label(&short_label(result.challenge));
+		let duration = match &result.memory {
+			Some(memory) => format!(
+				"{:.3}s {}",
+				result.duration.as_secs_f64(),
+				format_bytes(memory.peak)
+			),
+			None => format!("{:.3}s", result.duration.as_secs_f64()),
+		};
+		let duration = t.label(&duration);
+		match &result.result {
+			Ok(res) => {
+				let res = match result.expected {
+					Some(expected) if expected == *res => t.pass(&res.to_string()),
+					Some(expected) => t.fail(&format!("{}!={}", res, expected)),
+					None => res.to_string(),
+				};
+				println!("{} {} {}", label, res, duration)
+			}
+			Err(err) => {
+				println!("{} {} {}", label, t.fail(&err.to_string()), duration)
+			}
+		}
+	}
+}
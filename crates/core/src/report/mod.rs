@@ -0,0 +1,242 @@
+//! Everything the runner prints goes through a [`Reporter`].
+
+use std::io::IsTerminal;
+
+use clap::ValueEnum;
+use owo_colors::OwoColorize;
+
+use crate::{
+	common::{indented, Challenge, RunResult, TestResults},
+	summary::{self, Summary},
+};
+
+mod compact;
+mod plain;
+mod pretty;
+
+pub use compact::Compact;
+pub use plain::Plain;
+pub use pretty::Pretty;
+
+pub trait Reporter: Sync {
+	fn theme(&self) -> Theme;
+
+	/// The unit test results of a single challenge.
+	fn unit_tests(&self, results: &TestResults);
+
+	/// The result of a single challenge's real run.
+	fn run(&self, result: &RunResult);
+
+	/// Announces a phase of the run.
+	fn heading(&self, text: &str) {
+		println!("\n{}\n", text);
+	}
+
+	fn warning(&self, text: &str) {
+		println!("{}", self.theme().warn(text));
+	}
+
+	/// A line naming what the following lines are about.
+	fn label(&self, label: &str) {
+		println!("{}", self.theme().label(label));
+	}
+
+	/// An indented line under the last label or result.
+	fn detail(&self, text: &str) {
+		println!("  {}", text);
+	}
+
+	/// Output captured from a challenge.
+	fn output(&self, output: &str) {
+		print!("{}", indented(output, 1));
+	}
+
+	/// A challenge that couldn't be run at all, e.g. for lack of an input.
+	fn run_error(&self, challenge: &Challenge, err: &anyhow::Error) {
+		let t = self.theme();
+		println!(
+			"{} - {}",
+			t.label(&challenge.label()),
+			t.fail(&format!("Error - {}", err))
+		);
+	}
+
+	/// A challenge that wasn't run because its unit tests failed.
+	fn blocked(&self, challenge: &Challenge) {
+		let t = self.theme();
+		println!(
+			"{} - {}",
+			t.label(&challenge.label()),
+			t.warn("blocked by failing examples")
+		);
+	}
+
+	/// A one line account of how far challenges got.
+	fn summary_line(&self, summary: &Summary) {
+		let t = self.theme();
+		let mut line = format!(
+			"{}/{} challenges passed examples, {} ran, {} matched known answers",
+			summary.passed_examples(),
+			summary.tested,
+			summary.ran,
+			summary.matched
+		);
+		if summary.blocked > 0 {
+			line.push_str(&format!(
+				", {} blocked by failing examples",
+				summary.blocked
+			));
+		}
+		println!("\n{}", t.label(&line));
+	}
+
+	/// Totals of the whole run, and the exit code they result in.
+	fn summary(&self, summary: &Summary) {
+		let t = self.theme();
+		self.heading("Summary");
+		let count = |n: usize, text: &str| {
+			let line = format!("{} {}", n, text);
+			if n > 0 {
+				t.fail(&line)
+			} else {
+				t.label(&line)
+			}
+		};
+		println!(
+			"{} - {}/{} passed, {}",
+			t.label("Unit tests"),
+			summary.tested - summary.unit_test_failures,
+			summary.tested,
+			count(summary.unit_test_failures, "failed")
+		);
+		println!(
+			"{} - {} ran, {} matched known answers, {}, {}, {}",
+			t.label("Real runs"),
+			summary.ran,
+			summary.matched,
+			count(summary.run_errors, "errors"),
+			count(summary.timeouts, "timed out"),
+			count(summary.wrong_answers, "wrong answers")
+		);
+		if summary.blocked > 0 {
+			println!(
+				"{} - {}",
+				t.label("Blocked"),
+				t.warn(&format!("{} by failing examples", summary.blocked))
+			);
+		}
+		if summary.budget_exceeded {
+			println!("{} - {}", t.label("Budget"), t.fail("exceeded"));
+		}
+		let code = summary.exit_code().unwrap_or(0);
+		let reason = format!("{} ({})", code, summary::describe(code));
+		let reason = if code == 0 {
+			t.pass(&reason)
+		} else {
+			t.fail(&reason)
+		};
+		println!("{} - {}", t.label("Exit code"), reason);
+	}
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+pub enum ReporterKind {
+	/// Colors, symbols and failing inputs
+	Pretty,
+	/// The same layout in plain ASCII
+	Plain,
+	/// One line per challenge
+	Compact,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+pub enum ColorMode {
+	/// Color when stdout is a terminal and `NO_COLOR` isn't set
+	Auto,
+	Always,
+	Never,
+}
+
+impl ColorMode {
+	pub fn enabled(&self) -> bool {
+		match self {
+			ColorMode::Auto => {
+				std::env::var_os("NO_COLOR").is_none_or(|v| v.is_empty())
+					&& std::io::stdout().is_terminal()
+			}
+			ColorMode::Always => true,
+			ColorMode::Never => false,
+		}
+	}
+}
+
+pub fn reporter(kind: ReporterKind, color: ColorMode) -> Box<dyn Reporter> {
+	let theme = Theme::new(color.enabled());
+	match kind {
+		ReporterKind::Pretty => Box::new(Pretty::new(theme)),
+		ReporterKind::Plain => Box::new(Plain),
+		ReporterKind::Compact => Box::new(Compact::new(theme)),
+	}
+}
+
+/// Styles used by reporters. Only uses the terminal's own foreground and
+/// standard accent colors, so it reads on both light and dark backgrounds.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Theme {
+	color: bool,
+}
+
+impl Theme {
+	pub fn new(color: bool) -> Self {
+		Self { color }
+	}
+
+	pub fn plain() -> Self {
+		Self::new(false)
+	}
+
+	fn paint(&self, text: &str, style: impl Fn(&str) -> String) -> String {
+		if self.color {
+			style(text)
+		} else {
+			text.to_string()
+		}
+	}
+
+	/// Challenge labels, durations and other secondary text.
+	pub fn label(&self, text: &str) -> String {
+		self.paint(text, |t| t.dimmed().to_string())
+	}
+
+	pub fn pass(&self, text: &str) -> String {
+		self.paint(text, |t| t.green().to_string())
+	}
+
+	pub fn fail(&self, text: &str) -> String {
+		self.paint(text, |t| t.red().to_string())
+	}
+
+	pub fn warn(&self, text: &str) -> String {
+		self.paint(text, |t| t.yellow().to_string())
+	}
+
+	/// Challenges that are skipped.
+	pub fn skip(&self, text: &str) -> String {
+		self.paint(text, |t| t.blue().to_string())
+	}
+
+	/// Emphasized text in puzzle descriptions.
+	pub fn emphasis(&self, text: &str) -> String {
+		self.paint(text, |t| t.bold().to_string())
+	}
+
+	/// Code in puzzle descriptions.
+	pub fn code(&self, text: &str) -> String {
+		self.paint(text, |t| t.cyan().to_string())
+	}
+
+	/// A highlighted tag in front of a failure.
+	pub fn badge(&self, text: &str) -> String {
+		self.paint(text, |t| t.bold().white().on_red().to_string())
+	}
+}
@@ -0,0 +1,69 @@
+use crate::common::{indented, RunResult, TestResults, UnitTestResult};
+
+use super::{Reporter, Theme};
+
+/// The pretty layout without colors or symbols, for logs and dumb terminals.
+pub struct Plain;
+
+impl Reporter for Plain {
+	fn theme(&self) -> Theme {
+		Theme::plain()
+	}
+
+	fn unit_tests(&self, results: &TestResults) {
+		let label = results.challenge.label();
+
+		if results.challenge.skip {
+			println!("{} - skipped", label);
+			return;
+		}
+
+		let status = if results.passed() { "ok" } else { "FAILED" };
+		println!(
+			"{} - {}/{} {}",
+			label,
+			results.n_passed(),
+			results.results.len(),
+			status
+		);
+
+		for (i, res) in results.results.iter().enumerate() {
+			match res {
+				UnitTestResult::Equal => {}
+				UnitTestResult::NotEqual {
+					input,
+					expected,
+					got,
+				} => {
+					println!(
+						"  Test {}: expected {}, got {} for input:",
+						i, expected, got
+					);
+					print!("{}", indented(input, 2));
+				}
+				UnitTestResult::Other(err) => println!("  Test {}: error: {}", i, err),
+			}
+		}
+	}
+
+	fn run(&self, result: &RunResult) {
+		let label = result.challenge.label();
+		let duration = match &result.memory {
+			Some(memory) => {
+				format!("({}s, {})", result.duration.as_secs_f64(), memory.summary())
+			}
+			None => format!("({}s)", result.duration.as_secs_f64()),
+		};
+		match &result.result {
+			Ok(res) => {
+				let res = match result.expected {
+					Some(expected) if expected == *res => format!("{} ok", res),
+					Some(expected) => format!("{} WRONG, expected {}", res, expected),
+					None => res.to_string(),
+				};
+				println!("{} - {} {}", label, res, duration)
+			}
+			Err(err) => println!("{} - error: {} {}", label, err, duration),
+		}
+	}
+}
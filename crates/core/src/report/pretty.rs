@@ -0,0 +1,87 @@
+use crate::common::{RunResult, TestResults, UnitTestResult};
+
+use super::{Reporter, Theme};
+
+pub struct Pretty {
+	theme: Theme,
+}
+
+impl Pretty {
+	pub fn new(theme: Theme) -> Self {
+		Self { theme }
+	}
+}
+
+impl Reporter for Pretty {
+	fn theme(&self) -> Theme {
+		self.theme
+	}
+
+	fn unit_tests(&self, results: &TestResults) {
+		let t = self.theme;
+		let label = t.label(&results.challenge.label());
+
+		if results.challenge.skip {
+			println!("{} - {}", label, t.label("(skipped)"));
+			return;
+		}
+
+		let n_passed = results.n_passed();
+		let counts = format!("{}/{}", n_passed, results.results.len());
+		if results.passed() {
+			println!("{} - {}", label, t.pass(&format!("{} ✔️", counts)));
+			return;
+		}
+		println!("{} - {}", label, t.fail(&format!("{} ❌", counts)));
+
+		for (i, res) in results.results.iter().enumerate() {
+			let output = match res {
+				UnitTestResult::Equal => continue,
+				UnitTestResult::NotEqual {
+					input,
+					expected,
+					got,
+				} => format!(
+					"for input: \n\n{}\n\n---expected {}, got {}",
+					input, expected, got
+				),
+				UnitTestResult::Other(err) => format!("Error: {}", err),
+			};
+			println!(
+				"  {} {}",
+				t.badge(&format!(" Test {} ", i)),
+				t.fail(&output)
+			);
+		}
+	}
+
+	fn run(&self, result: &RunResult) {
+		let t = self.theme;
+		let label = t.label(&result.challenge.label());
+		let duration = match &result.memory {
+			Some(memory) => {
+				format!("({}s, {})", result.duration.as_secs_f64(), memory.summary())
+			}
+			None => format!("({}s)", result.duration.as_secs_f64()),
+		};
+		let duration = t.label(&duration);
+		match &result.result {
+			Ok(res) => {
+				let res = match result.expected {
+					Some(expected) if expected == *res => t.pass(&format!("{} ✔️", res)),
+					Some(expected) => {
+						t.fail(&format!("{} ❌ expected {}", res, expected))
+					}
+					None => res.to_string(),
+				};
+				println!("{} - {} {}", label, res, duration)
+			}
+			Err(err) => println!(
+				"{} - {} {}",
+				label,
+				t.fail(&format!("Error - {}", err)),
+				duration
+			),
+		}
+	}
+}
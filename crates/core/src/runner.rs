@@ -0,0 +1,115 @@
+//! Selecting challenges and running them, independently of how the results
+//! are reported.
+
+use std::{
+	collections::HashSet,
+	sync::atomic::{AtomicBool, Ordering},
+	time::{Duration, Instant},
+};
+
+use rayon::prelude::*;
+
+use crate::{
+	capture,
+	common::{all_challenges, Challenge, RunResult, TestResults},
+	progress::Progress,
+	store,
+	visual::{self, Recorder},
+};
+
+/// All registered challenges, optionally narrowed down to a year and day.
+pub fn select(
+	year: Option<usize>,
+	day: Option<usize>,
+) -> Result<Vec<&'static Challenge>, anyhow::Error> {
+	Ok(
+		all_challenges()?
+			.into_iter()
+			.filter(|c| year.is_none_or(|year| year == c.year))
+			.filter(|c| day.is_none_or(|day| day == c.day))
+			.collect(),
+	)
+}
+
+/// Runs the unit tests of every challenge in parallel, keeping their order.
+pub fn unit_tests(challenges: &[&'static Challenge]) -> Vec<TestResults> {
+	challenges.par_iter().map(|c| c.unit_test()).collect()
+}
+
+#[derive(Default)]
+pub struct RunOptions {
+	/// Give up on each challenge after this long.
+	pub timeout: Option<Duration>,
+	/// Stop starting challenges once one has failed.
+	pub fail_fast: bool,
+	/// Challenges not to run, e.g. because their unit tests failed.
+	pub blocked: HashSet<&'static Challenge>,
+}
+
+/// What became of a challenge in [`run`].
+pub enum Outcome {
+	Ran(RunResult),
+	/// The challenge couldn't be run, e.g. because its input is missing.
+	Failed(anyhow::Error),
+	Blocked,
+}
+
+/// Runs every challenge that isn't skipped on its real input in parallel,
+/// keeping their order. Challenges not started because of `fail_fast` are
+/// left out.
+pub fn run(
+	challenges: &[&'static Challenge],
+	options: &RunOptions,
+	progress: &Progress,
+) -> Vec<(&'static Challenge, Outcome)> {
+	let failed = AtomicBool::new(false);
+	challenges
+		.par_iter()
+		.filter(|c| !c.skip)
+		.filter_map(|&challenge| {
+			if options.blocked.contains(challenge) {
+				return Some((challenge, Outcome::Blocked));
+			}
+			if options.fail_fast && failed.load(Ordering::Relaxed) {
+				return None;
+			}
+			let _task = progress.start(challenge.label());
+			let res = match options.timeout {
+				Some(timeout) => challenge.run_with_timeout(timeout),
+				None => challenge.run_on_file(),
+			};
+			let ok = matches!(&res, Ok(r) if r.result.is_ok() && !r.wrong_answer());
+			if !ok {
+				failed.store(true, Ordering::Relaxed);
+			}
+			let outcome = match res {
+				Ok(result) => Outcome::Ran(result),
+				Err(err) => Outcome::Failed(err),
+			};
+			Some((challenge, outcome))
+		})
+		.collect()
+}
+
+/// Runs a challenge on its real input, feeding the frames it records to
+/// `recorder`. Returns the run along with how many frames were written.
+pub fn record(
+	challenge: &'static Challenge,
+	recorder: Recorder,
+) -> Result<(RunResult, Result<usize, anyhow::Error>), anyhow::Error> {
+	let input = store::read_input(challenge.year, challenge.day)?;
+	let start = Instant::now();
+	let ((result, output), frames) = visual::record::recording(recorder, || {
+		capture::capture(|| (challenge.f)(&input))
+	});
+	let result = RunResult {
+		challenge,
+		duration: start.elapsed(),
+		result,
+		output,
+		memory: None,
+		expected: store::known_answer(challenge)?,
+		timed_out: false,
+	};
+	Ok((result, frames))
+}
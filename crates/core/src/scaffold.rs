@@ -0,0 +1,130 @@
+//! Creating the module of a new day in its year's crate.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::anyhow;
+
+pub fn crate_dir(year: usize) -> PathBuf {
+	PathBuf::from(format!("./crates/y{}", year))
+}
+
+pub fn day_path(year: usize, day: usize) -> PathBuf {
+	crate_dir(year).join(format!("src/day{:0>2}.rs", day))
+}
+
+/// The imports every day relies on through `use super::*`.
+const LIB_HEADER: &str = "\
+use anyhow::anyhow;
+use aoc_core::{Challenge, ChallengeResult};
+use inventory::submit;
+";
+
+/// The dependencies of [`LIB_HEADER`].
+const DEPENDENCIES: [&str; 3] = [
+	"aoc-core.workspace = true",
+	"anyhow.workspace = true",
+	"inventory.workspace = true",
+];
+
+fn template(year: usize, day: usize) -> String {
+	let mut res =
+		String::from("use super::*;\n\nconst TEST_1: &str = \"\\\n\";\n");
+	for part in 1..=2 {
+		res.push_str(&format!(
+			"
+fn day{day:0>2}_{part}(_input: &str) -> ChallengeResult {{
+	Err(anyhow!(\"Not solved yet\"))
+}}
+
+submit!(Challenge {{
+	year: {year},
+	day: {day},
+	part: {part},
+	f: day{day:0>2}_{part},
+	unit_tests: &[(TEST_1, 0)],
+	skip: true,
+}});
+"
+		));
+	}
+	res
+}
+
+fn read(path: &Path) -> Result<String, anyhow::Error> {
+	std::fs::read_to_string(path)
+		.map_err(|err| anyhow!("Error reading {}: {}", path.display(), err))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), anyhow::Error> {
+	std::fs::write(path, contents)
+		.map_err(|err| anyhow!("Error writing {}: {}", path.display(), err))
+}
+
+/// Adds `mod dayNN;` to the year's `lib.rs`, keeping the days in order, and
+/// the imports and dependencies the days need if it's the first.
+fn declare(year: usize, day: usize) -> Result<(), anyhow::Error> {
+	let lib_path = crate_dir(year).join("src/lib.rs");
+	let lib = read(&lib_path)?;
+	let decl = format!("mod day{:0>2};", day);
+
+	let mut lines = lib.lines().map(String::from).collect::<Vec<String>>();
+	let days = lines
+		.iter()
+		.enumerate()
+		.filter(|(_, line)| line.starts_with("mod day"))
+		.map(|(i, line)| (i, line.clone()))
+		.collect::<Vec<(usize, String)>>();
+	if days.is_empty() {
+		let manifest_path = crate_dir(year).join("Cargo.toml");
+		let mut manifest = read(&manifest_path)?;
+		let missing = DEPENDENCIES
+			.into_iter()
+			.filter(|dep| !manifest.contains(dep))
+			.collect::<Vec<&str>>();
+		if !missing.is_empty() {
+			// the dependencies are the manifest's last section
+			if !manifest.contains("[dependencies]") {
+				manifest.push_str("\n[dependencies]\n");
+			}
+			for dep in missing {
+				manifest.push_str(dep);
+				manifest.push('\n');
+			}
+			write(&manifest_path, &manifest)?;
+		}
+		lines.push(String::new());
+		lines.extend(LIB_HEADER.lines().map(String::from));
+		lines.push(String::new());
+		lines.push(decl);
+	} else {
+		let at = days
+			.iter()
+			.find(|(_, line)| line.as_str() > decl.as_str())
+			.map(|(i, _)| *i)
+			.unwrap_or(days[days.len() - 1].0 + 1);
+		lines.insert(at, decl);
+	}
+	write(&lib_path, &(lines.join("\n") + "\n"))
+}
+
+/// Creates the module of a day from a template and declares it, returning
+/// its path, or `None` if it already exists.
+pub fn scaffold(
+	year: usize,
+	day: usize,
+) -> Result<Option<PathBuf>, anyhow::Error> {
+	if !crate_dir(year).exists() {
+		return Err(anyhow!(
+			"{} doesn't exist, add a crate for {} to the workspace first",
+			crate_dir(year).display(),
+			year
+		));
+	}
+	let path = day_path(year, day);
+	if path.exists() {
+		return Ok(None);
+	}
+	write(&path, &template(year, day))?;
+	declare(year, day)?;
+	Ok(Some(path))
+}
@@ -0,0 +1,87 @@
+//! Puzzle inputs and known answers on disk.
+//!
+//! Inputs live in `./inputs/<year>/<day>.txt`. Answers we've had accepted
+//! live next to them in `./answers/<year>/<day>.txt`, one line per part; an
+//! empty line means that part's answer isn't known yet. Saved copies of
+//! puzzle pages live in `./puzzles/<year>/<day>.html`. Inputs and answers
+//! may be encrypted, see [`crypt`], and are read from the binary instead
+//! when they were embedded into it, see [`embedded`].
+
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+
+use crate::{common::Challenge, crypt, embedded};
+
+pub fn input_path(year: usize, day: usize) -> PathBuf {
+	PathBuf::from(format!("./inputs/{}/{:0>2}.txt", year, day))
+}
+
+pub fn answer_path(year: usize, day: usize) -> PathBuf {
+	PathBuf::from(format!("./answers/{}/{:0>2}.txt", year, day))
+}
+
+pub fn puzzle_path(year: usize, day: usize) -> PathBuf {
+	PathBuf::from(format!("./puzzles/{}/{:0>2}.html", year, day))
+}
+
+/// Whether there's an input for a day, embedded or on disk.
+pub fn has_input(year: usize, day: usize) -> bool {
+	embedded::input(year, day).is_some() || input_path(year, day).exists()
+}
+
+pub fn read_input(year: usize, day: usize) -> Result<String, anyhow::Error> {
+	let path = input_path(year, day);
+	match embedded::input(year, day) {
+		Some(data) => crypt::decode(data.to_vec()).map_err(|err| {
+			anyhow!(
+				"Error reading embedded input for {} day {}: {}",
+				year,
+				day,
+				err
+			)
+		}),
+		None => crypt::read_to_string(&path)
+			.map_err(|err| anyhow!("Error reading {}: {}", path.display(), err)),
+	}
+}
+
+/// The known answers of each part of a day, indexed from part 1. A missing
+/// answer file means none are known.
+pub fn read_answers(
+	year: usize,
+	day: usize,
+) -> Result<Vec<Option<u128>>, anyhow::Error> {
+	let path = answer_path(year, day);
+	// where the answers came from, for errors
+	let (text, source) = match embedded::answers(year, day) {
+		Some(data) => (
+			crypt::decode(data.to_vec()),
+			format!("embedded answers for {} day {}", year, day),
+		),
+		None => (crypt::read_to_string(&path), path.display().to_string()),
+	};
+	let text = match text {
+		Ok(text) => text,
+		Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+			return Ok(Vec::new())
+		}
+		Err(err) => return Err(anyhow!("Error reading {}: {}", source, err)),
+	};
+	text
+		.lines()
+		.map(|line| match line.trim() {
+			"" => Ok(None),
+			answer => answer.parse().map(Some).map_err(|err| {
+				anyhow!("Invalid answer {} in {}: {}", answer, source, err)
+			}),
+		})
+		.collect()
+}
+
+pub fn known_answer(
+	challenge: &Challenge,
+) -> Result<Option<u128>, anyhow::Error> {
+	let answers = read_answers(challenge.year, challenge.day)?;
+	Ok(answers.get(challenge.part - 1).cloned().flatten())
+}
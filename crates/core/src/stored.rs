@@ -0,0 +1,39 @@
+//! Finding stored files by the `<root>/<year>/<day>.txt` layout of inputs
+//! and answers.
+//!
+//! Only uses std, as the build script includes it too to find the files to
+//! embed.
+
+use std::path::{Path, PathBuf};
+
+/// The year or day a file or directory is named after, like `2023` or
+/// `01.txt`.
+pub fn number(path: &Path) -> Option<usize> {
+	path.file_stem()?.to_str()?.parse().ok()
+}
+
+/// The year, day and path of each file under `root`, sorted. Anything not
+/// named after a year or day is skipped, and a missing `root` has none.
+pub fn files(root: &Path) -> std::io::Result<Vec<((usize, usize), PathBuf)>> {
+	let read_dir = |dir: &Path| -> std::io::Result<Vec<PathBuf>> {
+		match std::fs::read_dir(dir) {
+			Ok(entries) => entries.map(|entry| Ok(entry?.path())).collect(),
+			Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+			Err(err) => Err(err),
+		}
+	};
+
+	let mut files = Vec::new();
+	for year_dir in read_dir(root)? {
+		let Some(year) = number(&year_dir).filter(|_| year_dir.is_dir()) else {
+			continue;
+		};
+		for file in read_dir(&year_dir)? {
+			if let Some(day) = number(&file).filter(|_| file.is_file()) {
+				files.push(((year, day), file));
+			}
+		}
+	}
+	files.sort();
+	Ok(files)
+}
@@ -0,0 +1,99 @@
+//! Tallies of a whole run, and the exit code they map to.
+
+use std::process::ExitCode;
+
+use crate::common::{RunResult, TestResults};
+
+/// Exit codes, in order of precedence: when several kinds of failure
+/// happen, the lowest code wins. `1` is left for errors that stop the runner
+/// itself, like a malformed answer file.
+pub const WRONG_ANSWER: u8 = 2;
+pub const RUN_ERROR: u8 = 3;
+pub const TIMEOUT: u8 = 4;
+pub const UNIT_TEST_FAILURE: u8 = 5;
+pub const BUDGET_EXCEEDED: u8 = 6;
+
+pub fn describe(code: u8) -> &'static str {
+	match code {
+		0 => "success",
+		WRONG_ANSWER => "wrong answer",
+		RUN_ERROR => "run error",
+		TIMEOUT => "timeout",
+		UNIT_TEST_FAILURE => "unit test failure",
+		BUDGET_EXCEEDED => "budget exceeded",
+		_ => "error",
+	}
+}
+
+#[derive(Default, Debug)]
+pub struct Summary {
+	/// Challenges whose unit tests were run, and how many of them failed.
+	pub tested: usize,
+	pub unit_test_failures: usize,
+	/// Challenges run on their real input, and how those runs went.
+	pub ran: usize,
+	pub run_errors: usize,
+	pub timeouts: usize,
+	pub wrong_answers: usize,
+	/// Runs that gave the known answer.
+	pub matched: usize,
+	/// Challenges not run because their unit tests failed.
+	pub blocked: usize,
+	pub budget_exceeded: bool,
+}
+
+impl Summary {
+	pub fn add_unit_tests(&mut self, results: &TestResults) {
+		self.tested += 1;
+		if !results.passed() {
+			self.unit_test_failures += 1;
+		}
+	}
+
+	pub fn add_run(&mut self, result: &RunResult) {
+		self.ran += 1;
+		if result.timed_out {
+			self.timeouts += 1;
+		} else if result.result.is_err() {
+			self.run_errors += 1;
+		} else if result.wrong_answer() {
+			self.wrong_answers += 1;
+		} else if result.expected.is_some() {
+			self.matched += 1;
+		}
+	}
+
+	/// A challenge whose input couldn't even be read.
+	pub fn add_run_error(&mut self) {
+		self.ran += 1;
+		self.run_errors += 1;
+	}
+
+	pub fn passed_examples(&self) -> usize {
+		self.tested - self.unit_test_failures
+	}
+
+	pub fn failed(&self) -> bool {
+		self.exit_code().is_some()
+	}
+
+	pub fn exit_code(&self) -> Option<u8> {
+		if self.wrong_answers > 0 {
+			Some(WRONG_ANSWER)
+		} else if self.run_errors > 0 {
+			Some(RUN_ERROR)
+		} else if self.timeouts > 0 {
+			Some(TIMEOUT)
+		} else if self.unit_test_failures > 0 {
+			Some(UNIT_TEST_FAILURE)
+		} else if self.budget_exceeded {
+			Some(BUDGET_EXCEEDED)
+		} else {
+			None
+		}
+	}
+
+	pub fn exit(&self) -> ExitCode {
+		ExitCode::from(self.exit_code().unwrap_or(0))
+	}
+}
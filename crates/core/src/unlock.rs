@@ -0,0 +1,282 @@
+//! When puzzles unlock, and getting a day ready the moment it does.
+//!
+//! Everything that depends on the time goes through a [`Clock`], so the
+//! whole flow can be run against a [`SimulatedClock`] and a local stand-in
+//! for the site without waiting for December.
+
+use std::{
+	cell::Cell,
+	io::{IsTerminal, Write},
+	path::Path,
+	time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::anyhow;
+
+use crate::{
+	fetch::Client,
+	journal::{self, Event, Record},
+	puzzle,
+	report::Reporter,
+	scaffold, store,
+};
+
+/// Puzzles unlock at midnight US-Eastern, which is UTC-5 in December.
+const UNLOCK_HOUR_UTC: u64 = 5;
+/// How many times to retry a download that fails right at unlock, a second
+/// apart, before giving up.
+const RETRIES: usize = 5;
+
+pub trait Clock {
+	fn now(&self) -> SystemTime;
+	fn sleep(&self, duration: Duration);
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+	fn now(&self) -> SystemTime {
+		SystemTime::now()
+	}
+
+	fn sleep(&self, duration: Duration) {
+		std::thread::sleep(duration)
+	}
+}
+
+/// A clock that starts at a given time and only moves when slept on, so
+/// sleeping returns immediately.
+pub struct SimulatedClock(Cell<SystemTime>);
+
+impl SimulatedClock {
+	pub fn new(start: SystemTime) -> Self {
+		Self(Cell::new(start))
+	}
+}
+
+impl Clock for SimulatedClock {
+	fn now(&self) -> SystemTime {
+		self.0.get()
+	}
+
+	fn sleep(&self, duration: Duration) {
+		self.0.set(self.0.get() + duration)
+	}
+}
+
+/// Days since the unix epoch of a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+	let year = if month <= 2 { year - 1 } else { year };
+	let era = year.div_euclid(400);
+	let year_of_era = year - era * 400;
+	let month_index = (month + 9) % 12;
+	let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+	let day_of_era =
+		year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+	era * 146097 + day_of_era - 719468
+}
+
+/// The year a number of days since the unix epoch falls in.
+fn year_from_days(days: i64) -> i64 {
+	let days = days + 719468;
+	let era = days.div_euclid(146097);
+	let day_of_era = days - era * 146097;
+	let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524
+		- day_of_era / 146096)
+		/ 365;
+	let day_of_year =
+		day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+	let month_index = (5 * day_of_year + 2) / 153;
+	let year = year_of_era + era * 400;
+	if month_index >= 10 {
+		year + 1
+	} else {
+		year
+	}
+}
+
+/// How many days an event has: 25 until 2024, and 12 since.
+pub fn days_in(year: usize) -> usize {
+	if year >= 2025 {
+		12
+	} else {
+		25
+	}
+}
+
+/// When the puzzle of a day unlocks.
+pub fn unlock_time(year: usize, day: usize) -> SystemTime {
+	let days = days_from_civil(year as i64, 12, day as i64) as u64;
+	UNIX_EPOCH + Duration::from_secs(days * 86400 + UNLOCK_HOUR_UTC * 3600)
+}
+
+/// The first puzzle to unlock after `now`.
+pub fn next_unlock(now: SystemTime) -> (usize, usize) {
+	let secs = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+	let year = year_from_days((secs / 86400) as i64) as usize;
+	(1..=days_in(year))
+		.map(|day| (year, day))
+		.find(|&(year, day)| unlock_time(year, day) > now)
+		.unwrap_or((year + 1, 1))
+}
+
+/// The latest event that has started by `now`.
+pub fn latest_event(now: SystemTime) -> usize {
+	match next_unlock(now) {
+		(year, 1) => year - 1,
+		(year, _) => year,
+	}
+}
+
+fn format_remaining(remaining: Duration) -> String {
+	let secs = remaining.as_secs();
+	let (days, secs) = (secs / 86400, secs % 86400);
+	let clock = format!(
+		"{:0>2}:{:0>2}:{:0>2}",
+		secs / 3600,
+		secs % 3600 / 60,
+		secs % 60
+	);
+	if days > 0 {
+		format!("{}d {}", days, clock)
+	} else {
+		clock
+	}
+}
+
+/// Waits until the puzzle of a day unlocks, counting down on the terminal.
+pub fn wait(
+	clock: &dyn Clock,
+	year: usize,
+	day: usize,
+	reporter: &dyn Reporter,
+) {
+	let unlock = unlock_time(year, day);
+	let live = std::io::stdout().is_terminal();
+	let t = reporter.theme();
+	if let Ok(remaining) = unlock.duration_since(clock.now()) {
+		reporter.detail(&format!(
+			"{} day {} unlocks in {}",
+			year,
+			day,
+			format_remaining(remaining)
+		));
+	}
+	while let Ok(remaining) = unlock.duration_since(clock.now()) {
+		if remaining.is_zero() {
+			break;
+		}
+		if live {
+			print!("\r  {} ", t.label(&format_remaining(remaining)));
+			let _ = std::io::stdout().flush();
+		}
+		// tick less often while the unlock is far off
+		let tick = if remaining > Duration::from_secs(3600) {
+			Duration::from_secs(60)
+		} else {
+			Duration::from_secs(1)
+		};
+		clock.sleep(remaining.min(tick));
+	}
+	if live {
+		println!();
+	}
+}
+
+/// Downloads with a few retries, since the site can lag behind the unlock.
+fn download(
+	clock: &dyn Clock,
+	f: impl Fn() -> Result<String, anyhow::Error>,
+) -> Result<String, anyhow::Error> {
+	let mut attempt = 0;
+	loop {
+		match f() {
+			Ok(res) => return Ok(res),
+			Err(err) if attempt >= RETRIES => return Err(err),
+			Err(_) => {
+				attempt += 1;
+				clock.sleep(Duration::from_secs(1));
+			}
+		}
+	}
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), anyhow::Error> {
+	if let Some(dir) = path.parent() {
+		std::fs::create_dir_all(dir)
+			.map_err(|err| anyhow!("Error creating {}: {}", dir.display(), err))?;
+	}
+	std::fs::write(path, contents)
+		.map_err(|err| anyhow!("Error writing {}: {}", path.display(), err))
+}
+
+/// Opens a file with the desktop's default application.
+fn open(path: &Path) -> Result<(), anyhow::Error> {
+	let opener = if cfg!(target_os = "macos") {
+		"open"
+	} else if cfg!(target_os = "windows") {
+		"explorer"
+	} else {
+		"xdg-open"
+	};
+	std::process::Command::new(opener)
+		.arg(path)
+		.spawn()
+		.map(|_| ())
+		.map_err(|err| anyhow!("Error opening {}: {}", path.display(), err))
+}
+
+/// Waits for the puzzle of a day to unlock, then scaffolds its module,
+/// noting when in the journal, downloads its input unless it's already
+/// there, and saves, archives and opens a copy of its puzzle page.
+pub fn prepare(
+	clock: &dyn Clock,
+	client: &Client,
+	year: usize,
+	day: usize,
+	open_page: bool,
+	reporter: &dyn Reporter,
+) -> Result<(), anyhow::Error> {
+	wait(clock, year, day, reporter);
+
+	match scaffold::scaffold(year, day)? {
+		Some(path) => {
+			let timestamp = clock
+				.now()
+				.duration_since(UNIX_EPOCH)
+				.unwrap_or_default()
+				.as_secs();
+			journal::record(vec![Record {
+				year,
+				day,
+				part: None,
+				event: Event::Scaffolded,
+				timestamp,
+			}])?;
+			reporter.detail(&format!("created {}", path.display()))
+		}
+		None => reporter.detail(&format!("{} day {} already exists", year, day)),
+	}
+
+	let input_path = store::input_path(year, day);
+	if input_path.exists() {
+		reporter.detail(&format!("{} already exists", input_path.display()));
+	} else {
+		let input = download(clock, || client.input(year, day))?;
+		write(&input_path, &input)?;
+		reporter.detail(&format!("downloaded {}", input_path.display()));
+	}
+
+	let puzzle_path = store::puzzle_path(year, day);
+	let puzzle = download(clock, || client.puzzle(year, day))?;
+	write(&puzzle_path, &puzzle)?;
+	reporter.detail(&format!("saved {}", puzzle_path.display()));
+	if let Some(path) = puzzle::archive(year, day)? {
+		reporter.detail(&format!("archived {}", path.display()));
+	}
+	if open_page {
+		open(&puzzle_path)?;
+	}
+
+	Ok(())
+}
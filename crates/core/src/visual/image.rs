@@ -0,0 +1,51 @@
+use std::io::Write;
+
+use super::Grid;
+
+/// Expands `grid` into RGB pixel data, with every cell `scale` pixels wide.
+pub fn pixels(grid: &Grid, scale: u32) -> (u32, u32, Vec<u8>) {
+	let scale = scale.max(1) as usize;
+	let width = grid.width() * scale;
+	let height = grid.height() * scale;
+	let mut data = Vec::with_capacity(width * height * 3);
+	for row in grid.rows() {
+		let mut line = Vec::with_capacity(width * 3);
+		for cell in row {
+			let px = cell.pixel();
+			for _ in 0..scale {
+				line.extend([px.0, px.1, px.2]);
+			}
+		}
+		for _ in 0..scale {
+			data.extend(&line);
+		}
+	}
+	(width as u32, height as u32, data)
+}
+
+pub fn write_ppm(
+	grid: &Grid,
+	scale: u32,
+	mut writer: impl Write,
+) -> Result<(), anyhow::Error> {
+	let (width, height, data) = pixels(grid, scale);
+	write!(writer, "P6\n{} {}\n255\n", width, height)?;
+	writer.write_all(&data)?;
+	writer.flush()?;
+	Ok(())
+}
+
+pub fn write_png(
+	grid: &Grid,
+	scale: u32,
+	writer: impl Write,
+) -> Result<(), anyhow::Error> {
+	let (width, height, data) = pixels(grid, scale);
+	let mut encoder = png::Encoder::new(writer, width, height);
+	encoder.set_color(png::ColorType::Rgb);
+	encoder.set_depth(png::BitDepth::Eight);
+	let mut writer = encoder.write_header()?;
+	writer.write_image_data(&data)?;
+	writer.finish()?;
+	Ok(())
+}
@@ -0,0 +1,206 @@
+//! Rendering of puzzle state for debugging.
+//!
+//! A day implements [`Visualize`] for whatever state it wants to look at and
+//! submits a [`Visualizer`] that builds that state from an input. The runner
+//! invokes visualizers with `--visualize <backend>`.
+//!
+//! Simulations can also push snapshots to a [`Recorder`] with [`record`] to
+//! be exported as an animation with `--record <animation>`.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::anyhow;
+use clap::ValueEnum;
+
+mod image;
+pub mod record;
+mod terminal;
+
+pub use record::{record, Animation, Recorder};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+	pub const RED: Rgb = Rgb(220, 50, 47);
+	pub const GREEN: Rgb = Rgb(80, 180, 60);
+	pub const BLUE: Rgb = Rgb(38, 139, 210);
+	pub const YELLOW: Rgb = Rgb(230, 190, 40);
+	pub const PURPLE: Rgb = Rgb(160, 80, 200);
+	pub const WHITE: Rgb = Rgb(255, 255, 255);
+	pub const GREY: Rgb = Rgb(128, 128, 128);
+	pub const BLACK: Rgb = Rgb(0, 0, 0);
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Cell {
+	pub glyph: char,
+	pub color: Option<Rgb>,
+}
+
+impl Cell {
+	pub fn new(glyph: char) -> Self {
+		Self { glyph, color: None }
+	}
+
+	pub fn colored(glyph: char, color: Rgb) -> Self {
+		Self {
+			glyph,
+			color: Some(color),
+		}
+	}
+
+	/// The color used for this cell when rendering to an image. Uncolored
+	/// cells are grey, or black if they're blank.
+	pub fn pixel(&self) -> Rgb {
+		match self.color {
+			Some(color) => color,
+			None if self.glyph == ' ' || self.glyph == '.' => Rgb::BLACK,
+			None => Rgb::GREY,
+		}
+	}
+}
+
+impl Default for Cell {
+	fn default() -> Self {
+		Self::new(' ')
+	}
+}
+
+/// A rectangular grid of cells, stored row by row from the top left.
+#[derive(Clone, Debug)]
+pub struct Grid {
+	width: usize,
+	height: usize,
+	cells: Vec<Cell>,
+}
+
+impl Grid {
+	pub fn new(width: usize, height: usize) -> Self {
+		Self {
+			width,
+			height,
+			cells: vec![Cell::default(); width * height],
+		}
+	}
+
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	pub fn height(&self) -> usize {
+		self.height
+	}
+
+	pub fn get(&self, x: usize, y: usize) -> Option<&Cell> {
+		if x >= self.width || y >= self.height {
+			return None;
+		}
+		self.cells.get(y * self.width + x)
+	}
+
+	/// Sets the cell at `x`, `y`. Out of bounds positions are ignored.
+	pub fn set(&mut self, x: usize, y: usize, cell: Cell) {
+		if x < self.width && y < self.height {
+			self.cells[y * self.width + x] = cell;
+		}
+	}
+
+	pub fn rows(&self) -> impl Iterator<Item = &[Cell]> {
+		self.cells.chunks(self.width.max(1))
+	}
+}
+
+/// Puzzle state that can be drawn as a grid.
+pub trait Visualize {
+	fn visualize(&self) -> Grid;
+}
+
+pub type VisualizerFn = fn(&str) -> Result<Grid, anyhow::Error>;
+
+/// Registers a way to visualize a day's input, in the same fashion as
+/// [`crate::common::Challenge`].
+pub struct Visualizer {
+	pub year: usize,
+	pub day: usize,
+	pub f: VisualizerFn,
+}
+
+inventory::collect!(Visualizer);
+
+impl Visualizer {
+	pub fn label(&self) -> String {
+		format!("{} :: Day {:0>2}", self.year, self.day)
+	}
+
+	/// Where image backends write this visualizer's output.
+	pub fn out_path(&self, dir: &Path, backend: Backend) -> PathBuf {
+		dir.join(format!(
+			"{}-{:0>2}.{}",
+			self.year,
+			self.day,
+			backend.extension()
+		))
+	}
+}
+
+pub fn all_visualizers() -> Vec<&'static Visualizer> {
+	let mut res = inventory::iter::<Visualizer>
+		.into_iter()
+		.collect::<Vec<&Visualizer>>();
+	res.sort_by_key(|v| (v.year, v.day));
+	res
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+pub enum Backend {
+	/// Colored glyphs on stdout
+	Terminal,
+	/// Uncolored glyphs on stdout
+	Plain,
+	/// A PNG image file
+	Png,
+	/// A binary PPM image file
+	Ppm,
+}
+
+impl Backend {
+	pub fn extension(&self) -> &'static str {
+		match self {
+			Backend::Terminal | Backend::Plain => "txt",
+			Backend::Png => "png",
+			Backend::Ppm => "ppm",
+		}
+	}
+}
+
+/// Renders `grid` with `backend`. Text backends return the rendered text,
+/// image backends write to `path` and return nothing. `scale` is the size of
+/// each cell in pixels.
+pub fn render(
+	grid: &Grid,
+	backend: Backend,
+	path: &Path,
+	scale: u32,
+) -> Result<Option<String>, anyhow::Error> {
+	match backend {
+		Backend::Terminal => Ok(Some(terminal::colored(grid))),
+		Backend::Plain => Ok(Some(terminal::plain(grid))),
+		Backend::Png | Backend::Ppm => {
+			if let Some(parent) = path.parent() {
+				std::fs::create_dir_all(parent).map_err(|err| {
+					anyhow!("Error creating {}: {}", parent.display(), err)
+				})?;
+			}
+			let file = std::fs::File::create(path)
+				.map_err(|err| anyhow!("Error creating {}: {}", path.display(), err))?;
+			let writer = std::io::BufWriter::new(file);
+			if backend == Backend::Png {
+				image::write_png(grid, scale, writer)?;
+			} else {
+				image::write_ppm(grid, scale, writer)?;
+			}
+			Ok(None)
+		}
+	}
+}
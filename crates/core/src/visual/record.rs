@@ -0,0 +1,202 @@
+//! Recording of grid snapshots into animations.
+//!
+//! Solutions call [`record`] whenever their state changes in an interesting
+//! way. Outside of a recording that's a no-op that never builds the grid, so
+//! it's fine to leave the calls in.
+
+use std::{
+	cell::RefCell,
+	fs::File,
+	io::BufWriter,
+	path::{Path, PathBuf},
+};
+
+use anyhow::anyhow;
+use clap::ValueEnum;
+
+use super::{image, Grid};
+
+thread_local! {
+	static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+}
+
+/// Pushes a frame built by `frame` to the current thread's recorder, if any.
+pub fn record(frame: impl FnOnce() -> Grid) {
+	RECORDER.with(|r| {
+		if let Some(recorder) = r.borrow_mut().as_mut() {
+			recorder.push(frame);
+		}
+	});
+}
+
+/// Puts back the recorder that was active before one started when dropped,
+/// so a challenge that panics doesn't leave its thread recording.
+struct Restore(Option<Recorder>);
+
+impl Drop for Restore {
+	fn drop(&mut self) {
+		let outer = self.0.take();
+		RECORDER.with(|r| r.replace(outer));
+	}
+}
+
+/// Runs `f` with `recorder` receiving its frames, returning its result
+/// along with the number of frames written.
+pub fn recording<R>(
+	recorder: Recorder,
+	f: impl FnOnce() -> R,
+) -> (R, Result<usize, anyhow::Error>) {
+	let _restore = Restore(RECORDER.with(|r| r.replace(Some(recorder))));
+	let res = f();
+	let frames = RECORDER
+		.with(|r| r.take())
+		.ok_or_else(|| anyhow!("The recorder was taken during the recording"))
+		.and_then(|recorder| recorder.finish());
+	(res, frames)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, ValueEnum)]
+pub enum Animation {
+	/// An animated GIF
+	Gif,
+	/// A directory of numbered PNG images
+	Frames,
+}
+
+impl Animation {
+	/// Where the animation for `name` is written inside `dir`.
+	pub fn out_path(&self, dir: &Path, name: &str) -> PathBuf {
+		match self {
+			Animation::Gif => dir.join(format!("{}.gif", name)),
+			Animation::Frames => dir.join(name),
+		}
+	}
+}
+
+enum Sink {
+	Gif {
+		path: PathBuf,
+		encoder: Option<gif::Encoder<BufWriter<File>>>,
+		size: (u32, u32),
+	},
+	Frames(PathBuf),
+}
+
+/// Receives frames and streams them to disk, so long simulations don't have
+/// to hold every frame in memory.
+pub struct Recorder {
+	sink: Sink,
+	scale: u32,
+	every: usize,
+	/// Frame delay in hundredths of a second, for GIFs.
+	delay: u16,
+	pushed: usize,
+	written: usize,
+	error: Option<anyhow::Error>,
+}
+
+impl Recorder {
+	/// Creates a recorder writing to `path`, keeping one in every `every`
+	/// frames pushed to it.
+	pub fn new(
+		animation: Animation,
+		path: PathBuf,
+		scale: u32,
+		every: usize,
+	) -> Self {
+		let sink = match animation {
+			Animation::Gif => Sink::Gif {
+				path,
+				encoder: None,
+				size: (0, 0),
+			},
+			Animation::Frames => Sink::Frames(path),
+		};
+		Self {
+			sink,
+			scale,
+			every: every.max(1),
+			delay: 5,
+			pushed: 0,
+			written: 0,
+			error: None,
+		}
+	}
+
+	fn push(&mut self, frame: impl FnOnce() -> Grid) {
+		let keep = self.pushed.is_multiple_of(self.every);
+		self.pushed += 1;
+		if !keep || self.error.is_some() {
+			return;
+		}
+		match self.write(&frame()) {
+			Ok(()) => self.written += 1,
+			Err(err) => self.error = Some(err),
+		}
+	}
+
+	fn write(&mut self, grid: &Grid) -> Result<(), anyhow::Error> {
+		match &mut self.sink {
+			Sink::Gif {
+				path,
+				encoder,
+				size,
+			} => {
+				let (width, height, data) = image::pixels(grid, self.scale);
+				if encoder.is_none() {
+					if width > u16::MAX as u32 || height > u16::MAX as u32 {
+						return Err(anyhow!("{}x{} is too big for a gif", width, height));
+					}
+					if let Some(parent) = path.parent() {
+						std::fs::create_dir_all(parent)?;
+					}
+					let file = File::create(&path).map_err(|err| {
+						anyhow!("Error creating {}: {}", path.display(), err)
+					})?;
+					let mut enc = gif::Encoder::new(
+						BufWriter::new(file),
+						width as u16,
+						height as u16,
+						&[],
+					)?;
+					enc.set_repeat(gif::Repeat::Infinite)?;
+					*encoder = Some(enc);
+					*size = (width, height);
+				}
+				if *size != (width, height) {
+					return Err(anyhow!(
+						"frame size changed from {}x{} to {}x{}",
+						size.0,
+						size.1,
+						width,
+						height
+					));
+				}
+				let mut frame =
+					gif::Frame::from_rgb_speed(width as u16, height as u16, &data, 10);
+				frame.delay = self.delay;
+				encoder.as_mut().unwrap().write_frame(&frame)?;
+			}
+			Sink::Frames(dir) => {
+				std::fs::create_dir_all(&dir).map_err(|err| {
+					anyhow!("Error creating {}: {}", dir.display(), err)
+				})?;
+				let path = dir.join(format!("{:0>5}.png", self.written));
+				let file = File::create(&path).map_err(|err| {
+					anyhow!("Error creating {}: {}", path.display(), err)
+				})?;
+				image::write_png(grid, self.scale, BufWriter::new(file))?;
+			}
+		}
+		Ok(())
+	}
+
+	fn finish(self) -> Result<usize, anyhow::Error> {
+		if let Some(err) = self.error {
+			return Err(err);
+		}
+		// dropping the gif encoder writes the trailer
+		drop(self.sink);
+		Ok(self.written)
+	}
+}
@@ -0,0 +1,28 @@
+use owo_colors::OwoColorize;
+
+use super::Grid;
+
+pub fn colored(grid: &Grid) -> String {
+	let mut res = String::new();
+	for row in grid.rows() {
+		for cell in row {
+			match cell.color {
+				Some(c) => {
+					res.push_str(&cell.glyph.truecolor(c.0, c.1, c.2).to_string())
+				}
+				None => res.push(cell.glyph),
+			}
+		}
+		res.push('\n');
+	}
+	res
+}
+
+pub fn plain(grid: &Grid) -> String {
+	let mut res = String::new();
+	for row in grid.rows() {
+		res.extend(row.iter().map(|cell| cell.glyph));
+		res.push('\n');
+	}
+	res
+}
@@ -0,0 +1,9 @@
+//! Data structures shared by the solutions of every year.
+
+pub mod counter;
+pub mod pos;
+pub mod two_way;
+
+pub use counter::Counter;
+pub use pos::Pos;
+pub use two_way::TwoWayHashMap;
@@ -0,0 +1,51 @@
+use std::{
+	fmt::{Debug, Display},
+	hash::Hash,
+	ops::{Add, Mul},
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pos<T: PartialEq + Eq + Hash = i32> {
+	pub x: T,
+	pub y: T,
+}
+
+impl<T: PartialEq + Eq + Hash> Pos<T> {
+	pub fn new(x: T, y: T) -> Self {
+		Self { x, y }
+	}
+}
+
+impl<T: PartialEq + Eq + Hash + Add<Output = T>> Add for Pos<T> {
+	type Output = Pos<T>;
+
+	fn add(self, rhs: Self) -> Self::Output {
+		Pos {
+			x: self.x + rhs.x,
+			y: self.y + rhs.y,
+		}
+	}
+}
+
+impl<T: PartialEq + Eq + Hash + Mul<Output = T> + Copy> Mul<T> for Pos<T> {
+	type Output = Pos<T>;
+
+	fn mul(self, rhs: T) -> Self::Output {
+		Pos {
+			x: self.x * rhs,
+			y: self.y * rhs,
+		}
+	}
+}
+
+impl<T: PartialEq + Eq + Hash + Display> Display for Pos<T> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "({}, {})", self.x, self.y)
+	}
+}
+
+impl<T: PartialEq + Eq + Hash + Debug> Debug for Pos<T> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "({:?}, {:?})", self.x, self.y)
+	}
+}
@@ -1,10 +1,13 @@
 use std::ops::RangeInclusive;
 
+use aoc_core::visual::{record, Cell, Grid, Rgb, Visualize};
+
 use super::*;
 
 trait Light {
 	fn handle_command(&self, command: &CommandAction) -> Self;
 	fn brightness(&self) -> i32;
+	fn cell(&self) -> Cell;
 }
 
 #[derive(Clone, Copy, Default)]
@@ -25,6 +28,13 @@ impl Light for ToggleLight {
 			0
 		}
 	}
+	fn cell(&self) -> Cell {
+		if self.0 {
+			Cell::colored('#', Rgb::YELLOW)
+		} else {
+			Cell::new(' ')
+		}
+	}
 }
 
 #[derive(Clone, Copy, Default)]
@@ -42,6 +52,11 @@ impl Light for FaderLight {
 	fn brightness(&self) -> i32 {
 		self.0
 	}
+
+	fn cell(&self) -> Cell {
+		let level = (self.0 * 8).min(255) as u8;
+		Cell::colored('#', Rgb(level, level, level))
+	}
 }
 
 struct LightGrid<L: Light + Copy + Default>(Vec<Vec<L>>);
@@ -79,6 +94,19 @@ impl<L: Light + Copy + Default> LightGrid<L> {
 	}
 }
 
+impl<L: Light + Copy + Default> Visualize for LightGrid<L> {
+	fn visualize(&self) -> Grid {
+		let width = self.0.first().map(|row| row.len()).unwrap_or(0);
+		let mut grid = Grid::new(width, self.0.len());
+		for (y, row) in self.0.iter().enumerate() {
+			for (x, cell) in row.iter().enumerate() {
+				grid.set(x, y, cell.cell());
+			}
+		}
+		grid
+	}
+}
+
 fn parse_range(range: &str) -> Result<(usize, usize), anyhow::Error> {
 	let (x, y) = range
 		.split_once(",")
@@ -163,6 +191,7 @@ fn day06_1(input: &str) -> ChallengeResult {
 
 	for command in &commands {
 		grid.change(command)?;
+		record(|| grid.visualize());
 	}
 
 	Ok(grid.brightness() as u128)
@@ -191,6 +220,7 @@ fn day06_2(input: &str) -> ChallengeResult {
 
 	for command in &commands {
 		grid.change(command)?;
+		record(|| grid.visualize());
 	}
 
 	Ok(grid.brightness() as u128)
@@ -1,4 +1,4 @@
-use crate::common::ChallengeFn;
+use aoc_core::ChallengeFn;
 
 use super::*;
 
@@ -0,0 +1,6 @@
+//! Solutions to Advent of Code 2022.
+
+// lets print! and println! in solutions be captured, see aoc_core::capture
+#[allow(unused_imports)]
+#[macro_use]
+extern crate aoc_core;
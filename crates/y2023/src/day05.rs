@@ -111,7 +111,6 @@ impl Almanac {
 	}
 
 	pub fn smallest_location(self, use_ranges: bool) -> i64 {
-		use indicatif::ParallelProgressIterator;
 		use itertools::Itertools;
 		use rayon::prelude::*;
 		if use_ranges {
@@ -127,9 +126,15 @@ impl Almanac {
 				.next()
 				.unwrap();
 
+			let progress = aoc_core::progress::current();
 			let res = (0..=max_location)
 				.into_par_iter()
 				.find_first(|loc| {
+					if let Some(progress) = &progress {
+						if loc % 100_000 == 0 {
+							progress.report(*loc as u64, max_location as u64);
+						}
+					}
 					let seed = self.run_backwards(*loc);
 					self.has_seed(seed, use_ranges)
 				})
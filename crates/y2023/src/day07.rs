@@ -1,4 +1,4 @@
-use crate::util::counter::Counter;
+use aoc_util::Counter;
 
 use super::*;
 
@@ -1,8 +1,7 @@
 use std::collections::HashSet;
 
-use owo_colors::OwoColorize;
-
-use crate::common::Pos;
+use aoc_core::visual::{record, Cell, Grid, Rgb, Visualize, Visualizer};
+use aoc_util::Pos;
 
 use super::*;
 
@@ -73,6 +72,7 @@ impl Map {
 		let next = tile.travel(&from)?;
 		let offset = next.offset();
 		vals.insert(pos);
+		record(|| self.loop_frame(&vals));
 		self._get_loop(vals, pos + offset, next.inverse())
 	}
 
@@ -151,33 +151,50 @@ impl Map {
 		}
 		return (visited, false);
 	}
+}
 
-	fn pretty_print(&self) {
+impl Map {
+	/// The map with the tiles of a partially traversed loop highlighted.
+	fn loop_frame(&self, loop_tiles: &HashSet<Pos>) -> Grid {
+		let mut grid = Grid::new(self.size.x as usize, self.size.y as usize);
+		for y in 0..self.size.y {
+			for x in 0..self.size.x {
+				let pos = Pos::new(x, -y);
+				let cell = if loop_tiles.contains(&pos) {
+					Cell::colored('#', Rgb::PURPLE)
+				} else if pos == self.start_pos {
+					Cell::colored('S', Rgb::YELLOW)
+				} else {
+					Cell::new('.')
+				};
+				grid.set(x as usize, y as usize, cell);
+			}
+		}
+		grid
+	}
+}
+
+impl Visualize for Map {
+	fn visualize(&self) -> Grid {
 		let loop_tiles = self.get_loop();
 		let (poisoned_tiles, enclosed_tiles) = self.enclosed();
-		let mut displ = String::new();
+		let mut grid = Grid::new(self.size.x as usize, self.size.y as usize);
 		for y in 0..self.size.y {
-			let mut row = String::new();
 			for x in 0..self.size.x {
-				let pos = Pos::new(x, y * -1);
-				if poisoned_tiles.contains(&pos) {
-					row.push_str(&"P".red().to_string());
-					continue;
-				}
-				if loop_tiles.contains(&pos) {
-					row.push_str(&"#".purple().to_string());
-					continue;
-				}
-				if enclosed_tiles.contains(&pos) {
-					row.push_str(&"I".green().to_string());
-					continue;
-				}
-				row.push('.');
+				let pos = Pos::new(x, -y);
+				let cell = if poisoned_tiles.contains(&pos) {
+					Cell::colored('P', Rgb::RED)
+				} else if loop_tiles.contains(&pos) {
+					Cell::colored('#', Rgb::PURPLE)
+				} else if enclosed_tiles.contains(&pos) {
+					Cell::colored('I', Rgb::GREEN)
+				} else {
+					Cell::new('.')
+				};
+				grid.set(x as usize, y as usize, cell);
 			}
-			displ.push_str(&row);
-			displ.push('\n');
 		}
-		println!("{}\n", displ);
+		grid
 	}
 }
 
@@ -291,7 +308,6 @@ LJ.LJ
 
 fn day10_1(input: &str) -> ChallengeResult {
 	let map = Map::parse(input);
-	map.pretty_print();
 	let res = map.get_loop().len() / 2;
 
 	Ok(res as u128)
@@ -343,7 +359,6 @@ L7JLJL-JLJLJL--JLJ.L";
 
 fn day10_2(input: &str) -> ChallengeResult {
 	let map = Map::parse(input);
-	map.pretty_print();
 	let res = map.enclosed().1.len();
 
 	Ok(res as u128)
@@ -357,3 +372,9 @@ submit!(Challenge {
 	unit_tests: &[(TEST2_1, 4), (TEST2_2, 8), (TEST2_3, 10)],
 	skip: false,
 });
+
+submit!(Visualizer {
+	year: 2023,
+	day: 10,
+	f: |input| Ok(Map::parse(input).visualize()),
+});
@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use itertools::Itertools;
 
-use crate::common::Pos;
+use aoc_util::Pos;
 
 use super::*;
 
@@ -0,0 +1,22 @@
+//! Solutions to Advent of Code 2023.
+
+// lets print! and println! in solutions be captured, see aoc_core::capture
+#[allow(unused_imports)]
+#[macro_use]
+extern crate aoc_core;
+
+use anyhow::{anyhow, bail};
+use aoc_core::{Challenge, ChallengeResult};
+use inventory::submit;
+
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
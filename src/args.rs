@@ -0,0 +1,181 @@
+//! Command line arguments of the runner.
+
+use std::{
+	path::PathBuf,
+	time::{Duration, SystemTime},
+};
+
+use adventofcode::{fetch, report, visual};
+use clap::{Parser, Subcommand};
+
+#[derive(Parser, Debug)]
+#[command()]
+pub struct Args {
+	#[command(subcommand)]
+	pub command: Option<Command>,
+	#[arg(short, long)]
+	pub test_only: bool,
+	#[arg(short, long, global = true)]
+	pub year: Option<usize>,
+	#[arg(short, long, global = true)]
+	pub day: Option<usize>,
+	/// Print captured solution output even for passing challenges.
+	#[arg(long)]
+	pub show_output: bool,
+	/// Render the selected days' visualizations of their real input instead
+	/// of running them.
+	#[arg(long, value_name = "BACKEND")]
+	pub visualize: Option<visual::Backend>,
+	/// Directory image visualizations are written to.
+	#[arg(long, default_value = "./visualizations")]
+	pub visualize_dir: PathBuf,
+	/// Size of each grid cell in image visualizations, in pixels.
+	#[arg(long, default_value_t = 4)]
+	pub visualize_scale: u32,
+	/// Run the selected challenges on their real input, recording the frames
+	/// they push into an animation in the visualization directory.
+	#[arg(long, value_name = "ANIMATION")]
+	pub record: Option<visual::Animation>,
+	/// Only keep one in every N recorded frames.
+	#[arg(long, default_value_t = 1, value_name = "N")]
+	pub record_every: usize,
+	#[arg(
+		long,
+		global = true,
+		value_enum,
+		default_value_t = report::ReporterKind::Pretty
+	)]
+	pub reporter: report::ReporterKind,
+	#[arg(
+		long,
+		global = true,
+		value_enum,
+		default_value_t = report::ColorMode::Auto
+	)]
+	pub color: report::ColorMode,
+	/// Don't append this run's results to the history or the solve journal.
+	#[arg(long)]
+	pub no_history: bool,
+	/// Save this run's results and timings as a named baseline.
+	#[arg(long, value_name = "NAME")]
+	pub save_baseline: Option<String>,
+	/// Compare this run's results and timings against a named baseline.
+	#[arg(long, value_name = "NAME")]
+	pub baseline: Option<String>,
+	/// Fail if the real runs take longer than this in total, e.g. `1s`.
+	#[arg(long, value_parser = humantime::parse_duration)]
+	pub budget: Option<Duration>,
+	/// Fail if any year's real runs take longer than this in total.
+	#[arg(long, value_parser = humantime::parse_duration)]
+	pub year_budget: Option<Duration>,
+	/// Give up on a challenge's real run after this long, e.g. `10s`.
+	#[arg(long, value_parser = humantime::parse_duration)]
+	pub timeout: Option<Duration>,
+	/// Don't run challenges on their real input unless their unit tests
+	/// pass.
+	#[arg(long)]
+	pub gate: bool,
+	/// Stop at the first failing unit test, run error or wrong answer.
+	#[arg(long)]
+	pub fail_fast: bool,
+	/// Disable color and live progress, don't write the solve journal, and
+	/// finish with a summary.
+	#[arg(long)]
+	pub ci: bool,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+	/// Show timing trends of past runs on this machine.
+	History {
+		/// Flag challenges whose latest run is this many percent slower than
+		/// the run before it.
+		#[arg(long, default_value_t = 10.0)]
+		threshold: f64,
+		/// How many recent runs to show the trend of.
+		#[arg(long, default_value_t = 10)]
+		last: usize,
+	},
+	/// List every registered challenge with its examples, input, known
+	/// answer and the first line of its day's notes.
+	List {
+		/// Print the list as JSON.
+		#[arg(long)]
+		json: bool,
+	},
+	/// Show how many stars each year has.
+	Status {
+		/// Print the status as JSON.
+		#[arg(long)]
+		json: bool,
+	},
+	/// Show an advent calendar of each year's stars.
+	Calendar,
+	/// Show how long each part of the scaffolded days took to solve.
+	Journal,
+	/// Regenerate the progress section of the README from the answer store
+	/// and the history.
+	Readme {
+		#[arg(long, default_value = "./README.md")]
+		path: PathBuf,
+		/// How many recent runs to take the median runtime of.
+		#[arg(long, default_value_t = 10)]
+		last: usize,
+	},
+	/// Wait for the next puzzle to unlock, or the one given with `--year`
+	/// and `--day`, then scaffold its module, download its input and open a
+	/// copy of its page.
+	Unlock {
+		#[command(flatten)]
+		site: Site,
+		/// Pretend it's this time, e.g. `2023-11-30T23:59:50Z`, and skip the
+		/// wait instead of sleeping through it.
+		#[arg(long, value_parser = humantime::parse_rfc3339_weak)]
+		now: Option<SystemTime>,
+		/// Don't open the puzzle page.
+		#[arg(long)]
+		no_open: bool,
+	},
+	/// Show a private leaderboard of `--year`, or the latest event.
+	Leaderboard {
+		/// The leaderboard's ID, the number at the end of its URL.
+		#[arg(long, required_unless_present = "file")]
+		id: Option<u64>,
+		/// Read the leaderboard's JSON from this file instead.
+		#[arg(long, conflicts_with = "id")]
+		file: Option<PathBuf>,
+		#[command(flatten)]
+		site: Site,
+	},
+	/// Show the description of the puzzle of `--year` and `--day`, archived
+	/// as markdown next to its module from the saved puzzle page, and the
+	/// day's notes.
+	Show,
+	/// Encrypt the inputs and answers of `--year` and `--day`, or all of
+	/// them, with the passphrase from `AOC_PASSPHRASE` or `./.aoc/key`.
+	Encrypt,
+	/// Decrypt the inputs and answers of `--year` and `--day`, or all of
+	/// them.
+	Decrypt,
+}
+
+/// Where to download from and how to log in.
+#[derive(clap::Args, Debug)]
+pub struct Site {
+	/// The site to download from, or a local stand-in for it.
+	#[arg(long, env = "AOC_BASE_URL", default_value = fetch::DEFAULT_BASE_URL)]
+	pub base_url: String,
+	/// Session cookie to log in with. Read from `./.aoc/session` if not given.
+	#[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+	pub session: Option<String>,
+}
+
+impl Site {
+	pub fn client(&self) -> Result<fetch::Client, anyhow::Error> {
+		let session = match &self.session {
+			Some(session) => Some(session.clone()),
+			None => fetch::Client::saved_session()?,
+		};
+		Ok(fetch::Client::new(&self.base_url, session))
+	}
+}
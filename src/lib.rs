@@ -0,0 +1,67 @@
+//! Advent of Code solutions, the registry they submit themselves to, and
+//! the runner that tests and times them.
+//!
+//! The runner lives in `aoc-core`, shared data structures in `aoc-util` and
+//! each year's solutions in their own crate. Naming the year crates here
+//! links them in, so their challenges show up in the registry. Each year is
+//! behind a cargo feature of the same name, all on by default, so builds can
+//! leave out the years they don't need.
+
+pub use aoc_core::*;
+pub use aoc_util::{Counter, Pos, TwoWayHashMap};
+
+pub mod years {
+	#[cfg(feature = "y2015")]
+	pub use aoc_2015 as y2015;
+	#[cfg(feature = "y2016")]
+	pub use aoc_2016 as y2016;
+	#[cfg(feature = "y2017")]
+	pub use aoc_2017 as y2017;
+	#[cfg(feature = "y2018")]
+	pub use aoc_2018 as y2018;
+	#[cfg(feature = "y2019")]
+	pub use aoc_2019 as y2019;
+	#[cfg(feature = "y2020")]
+	pub use aoc_2020 as y2020;
+	#[cfg(feature = "y2021")]
+	pub use aoc_2021 as y2021;
+	#[cfg(feature = "y2022")]
+	pub use aoc_2022 as y2022;
+	#[cfg(feature = "y2023")]
+	pub use aoc_2023 as y2023;
+
+	/// Every year, and whether it's compiled in.
+	const YEARS: [(usize, bool); 9] = [
+		(2015, cfg!(feature = "y2015")),
+		(2016, cfg!(feature = "y2016")),
+		(2017, cfg!(feature = "y2017")),
+		(2018, cfg!(feature = "y2018")),
+		(2019, cfg!(feature = "y2019")),
+		(2020, cfg!(feature = "y2020")),
+		(2021, cfg!(feature = "y2021")),
+		(2022, cfg!(feature = "y2022")),
+		(2023, cfg!(feature = "y2023")),
+	];
+
+	/// The years whose solutions are compiled in.
+	pub fn compiled() -> Vec<usize> {
+		YEARS
+			.iter()
+			.filter(|(_, compiled)| *compiled)
+			.map(|(year, _)| *year)
+			.collect()
+	}
+
+	pub fn is_compiled(year: usize) -> bool {
+		YEARS.contains(&(year, true))
+	}
+
+	/// Every year with a feature, compiled in or not.
+	pub fn all() -> Vec<usize> {
+		YEARS.iter().map(|(year, _)| *year).collect()
+	}
+
+	pub fn has_feature(year: usize) -> bool {
+		YEARS.iter().any(|(y, _)| *y == year)
+	}
+}
@@ -1,99 +1,375 @@
-use std::time::Duration;
+use std::{collections::HashSet, process::ExitCode, time::SystemTime};
 
+use adventofcode::{
+	all_challenges, baseline, budget, calendar, crypt, history, journal,
+	leaderboard, list, progress, puzzle, readme, report,
+	report::{Reporter, Theme},
+	runner::{self, Outcome, RunOptions},
+	store,
+	summary::Summary,
+	unlock::{self, Clock, SimulatedClock, SystemClock},
+	visual, years, Challenge,
+};
+use anyhow::anyhow;
 use clap::Parser;
-use owo_colors::OwoColorize;
-use rayon::prelude::*;
-
-use crate::common::Challenge;
-
-pub mod years {
-	pub mod y2015;
-	pub mod y2016;
-	pub mod y2017;
-	pub mod y2018;
-	pub mod y2019;
-	pub mod y2020;
-	pub mod y2021;
-	pub mod y2022;
-	pub mod y2023;
-}
-pub mod common;
-pub mod util;
 
-fn main() -> Result<(), anyhow::Error> {
-	let args = common::Args::parse();
+use crate::args::{Args, Command};
 
-	let challenges = common::all_challenges()?
-		.into_iter()
-		.filter(|c| {
-			if let Some(year) = args.year {
-				if year != c.year {
-					return false;
-				}
-			}
-			if let Some(day) = args.day {
-				if day != c.day {
-					return false;
-				}
+mod args;
+
+fn main() -> Result<ExitCode, anyhow::Error> {
+	let args = Args::parse();
+	let color = if args.ci {
+		report::ColorMode::Never
+	} else {
+		args.color
+	};
+	let reporter = report::reporter(args.reporter, color);
+	let reporter = reporter.as_ref();
+
+	// these work for any year, compiled in or not
+	if let Some(Command::Unlock { .. }) = &args.command {
+		unlock(&args, reporter)?;
+		return Ok(ExitCode::SUCCESS);
+	}
+	if let Some(Command::Leaderboard { .. }) = &args.command {
+		leaderboard(&args, reporter)?;
+		return Ok(ExitCode::SUCCESS);
+	}
+	if let Some(Command::Show) = &args.command {
+		let (Some(year), Some(day)) = (args.year, args.day) else {
+			return Err(anyhow!("Give the puzzle to show with --year and --day"));
+		};
+		puzzle::show(year, day, reporter)?;
+		return Ok(ExitCode::SUCCESS);
+	}
+	if let Some(Command::Encrypt | Command::Decrypt) = &args.command {
+		let encrypting = matches!(args.command, Some(Command::Encrypt));
+		crypt::convert(args.year, args.day, encrypting, reporter)?;
+		return Ok(ExitCode::SUCCESS);
+	}
+
+	if let Some(year) = args.year.filter(|&year| !years::is_compiled(year)) {
+		let list = |years: Vec<usize>| {
+			if years.is_empty() {
+				"none".to_string()
+			} else {
+				years
+					.iter()
+					.map(|year| year.to_string())
+					.collect::<Vec<String>>()
+					.join(", ")
 			}
-			return true;
-		})
-		.collect::<Vec<&'static Challenge>>();
+		};
+		if !years::has_feature(year) {
+			return Err(anyhow!(
+				"There's no year {} (available years: {})",
+				year,
+				list(years::all())
+			));
+		}
+		return Err(anyhow!(
+			"Year {} isn't compiled in, build with `--features y{}` (compiled in: {})",
+			year,
+			year,
+			list(years::compiled())
+		));
+	}
+
+	let challenges = runner::select(args.year, args.day)?;
 
 	if challenges.len() == 0 {
-		println!("{}", "No challenges found!".yellow());
-		return Ok(());
+		reporter.warning("No challenges found!");
+		return Ok(ExitCode::SUCCESS);
 	}
 
-	println!("\nRunning unit tests....\n");
+	match args.command {
+		Some(Command::History { threshold, last }) => {
+			history::show(&challenges, reporter, threshold, last)?;
+			return Ok(ExitCode::SUCCESS);
+		}
+		Some(Command::List { json }) => {
+			list::list(&challenges, reporter, json)?;
+			return Ok(ExitCode::SUCCESS);
+		}
+		Some(Command::Status { json }) => {
+			list::status(&challenges, reporter, json)?;
+			return Ok(ExitCode::SUCCESS);
+		}
+		Some(Command::Journal) => {
+			journal::show(&challenges, reporter)?;
+			return Ok(ExitCode::SUCCESS);
+		}
+		Some(Command::Calendar) => {
+			calendar::show(&challenges, reporter)?;
+			return Ok(ExitCode::SUCCESS);
+		}
+		Some(Command::Readme { path, last }) => {
+			// the section covers every year, so a selection would drop the rest
+			if args.year.is_some() || args.day.is_some() {
+				return Err(anyhow!(
+					"The readme covers every year, run it without --year or --day"
+				));
+			}
+			// and a partial build would drop the years it leaves out
+			if years::compiled() != years::all() {
+				let missing = years::all()
+					.into_iter()
+					.filter(|year| !years::is_compiled(*year))
+					.map(|year| year.to_string())
+					.collect::<Vec<_>>();
+				return Err(anyhow!(
+					"The readme covers every year, but {} aren't compiled in",
+					missing.join(", ")
+				));
+			}
+			let challenges = all_challenges()?;
+			if readme::update(&path, &challenges, &years::all(), last)? {
+				reporter.detail(&format!("updated {}", path.display()));
+			} else {
+				reporter.detail(&format!("{} is up to date", path.display()));
+			}
+			return Ok(ExitCode::SUCCESS);
+		}
+		Some(
+			Command::Unlock { .. }
+			| Command::Leaderboard { .. }
+			| Command::Show
+			| Command::Encrypt
+			| Command::Decrypt,
+		)
+		| None => {}
+	}
 
-	let test_results: Vec<common::TestResults> =
-		challenges.par_iter().map(|item| item.unit_test()).collect();
+	if let Some(backend) = args.visualize {
+		visualize(&args, reporter, backend)?;
+		return Ok(ExitCode::SUCCESS);
+	}
 
-	for result in test_results {
-		println!("{}", result);
+	if let Some(animation) = args.record {
+		record(&args, reporter, animation, challenges)?;
+		return Ok(ExitCode::SUCCESS);
+	}
+
+	let mut summary = Summary::default();
+
+	reporter.heading("Running unit tests....");
+
+	// the journal is a personal log of solving, not of test or CI runs
+	let journaling = !args.no_history && !args.test_only && !args.ci;
+	let test_results = runner::unit_tests(&challenges);
+	if journaling {
+		journal::record(journal::examples_passed(&test_results, history::now()))?;
+	}
+
+	let mut failing = HashSet::new();
+	for result in &test_results {
+		if !result.passed() {
+			failing.insert(result.challenge);
+		}
+		reporter.unit_tests(result);
+		if args.show_output || !result.passed() {
+			reporter.output(&result.output());
+		}
+		summary.add_unit_tests(result);
+		if args.fail_fast && summary.failed() {
+			return Ok(finish(&args, reporter, &summary));
+		}
 	}
 
 	if args.test_only {
-		println!("test-only specified, skipping actual tests.");
+		reporter.heading("test-only specified, skipping actual tests.");
+		return Ok(finish(&args, reporter, &summary));
+	}
+
+	// load the baseline before running so a missing one fails fast
+	let baseline = match &args.baseline {
+		Some(name) => Some((name, baseline::load(name)?)),
+		None => None,
+	};
+
+	reporter.heading("Running actual tests...");
+
+	let progress = if args.ci {
+		progress::Progress::hidden()
+	} else {
+		progress::Progress::auto(reporter.theme())
+	};
+	let options = RunOptions {
+		timeout: args.timeout,
+		fail_fast: args.fail_fast,
+		blocked: if args.gate { failing } else { HashSet::new() },
+	};
+	let runs = runner::run(&challenges, &options, &progress);
+
+	let mut real_results = Vec::new();
+	for (challenge, outcome) in runs {
+		match outcome {
+			Outcome::Blocked => {
+				reporter.blocked(challenge);
+				summary.blocked += 1;
+			}
+			Outcome::Ran(result) => {
+				reporter.run(&result);
+				if args.show_output || result.result.is_err() {
+					reporter.output(&result.output);
+				}
+				summary.add_run(&result);
+				real_results.push(result);
+			}
+			Outcome::Failed(err) => {
+				reporter.run_error(challenge, &err);
+				summary.add_run_error();
+			}
+		}
+	}
+
+	if !args.no_history {
+		history::append(&real_results)?;
+	}
+	if journaling {
+		journal::record(journal::answered(&real_results, history::now()))?;
+	}
+
+	if let Some((name, baseline)) = baseline {
+		baseline::compare(name, &baseline, &real_results, reporter);
+	}
+
+	if let Some(name) = &args.save_baseline {
+		let path = baseline::save(name, &real_results)?;
+		reporter.detail(&format!("saved baseline to {}", path.display()));
+	}
+
+	if args.budget.is_some() || args.year_budget.is_some() {
+		summary.budget_exceeded =
+			budget::check(&real_results, args.budget, args.year_budget, reporter);
+	}
+
+	Ok(finish(&args, reporter, &summary))
+}
+
+/// Prints the summary, in full in CI mode, and picks the exit code.
+fn finish(args: &Args, reporter: &dyn Reporter, summary: &Summary) -> ExitCode {
+	if args.ci {
+		reporter.summary(summary);
+	} else {
+		reporter.summary_line(summary);
+	}
+	summary.exit()
+}
+
+fn visualize(
+	args: &Args,
+	reporter: &dyn Reporter,
+	backend: visual::Backend,
+) -> Result<(), anyhow::Error> {
+	let visualizers = visual::all_visualizers()
+		.into_iter()
+		.filter(|v| args.year.map(|year| year == v.year).unwrap_or(true))
+		.filter(|v| args.day.map(|day| day == v.day).unwrap_or(true))
+		.collect::<Vec<&visual::Visualizer>>();
+
+	if visualizers.is_empty() {
+		reporter.warning("No visualizations found!");
 		return Ok(());
 	}
 
-	println!("\nRunning actual tests...\n");
-
-	let real_results = challenges
-		.into_par_iter()
-		.filter(|c| !c.skip)
-		.map(|challenge| {
-			challenge
-				.run_on_file()
-				.map(|(duration, result)| (challenge, duration, result))
-		})
-		.collect::<Result<
-			Vec<(&Challenge, Duration, Result<u128, anyhow::Error>)>,
-			anyhow::Error,
-		>>()?;
-
-	for (challenge, duration, result) in real_results {
-		match result {
-			Ok(res) => println!(
-				"{} - {} {}",
-				challenge.label().black(),
-				res,
-				format!("({}s)", duration.as_secs_f64()).black(),
-			),
-			Err(err) => println!(
-				"{} - {} {}",
-				challenge.label().black(),
-				format!("Error - {}", err).red(),
-				format!("({}s)", duration.as_secs_f64()).black(),
-			),
-		}
-	}
-
-	// years
-	// 	.run_on_files()
-	// 	.expect("Got an error while running on files");
+	// respect `--color never` and `NO_COLOR` like everything else
+	let backend = match backend {
+		visual::Backend::Terminal if reporter.theme() == Theme::plain() => {
+			visual::Backend::Plain
+		}
+		backend => backend,
+	};
+
+	for visualizer in visualizers {
+		let input = store::read_input(visualizer.year, visualizer.day)?;
+		let grid = (visualizer.f)(&input)?;
+		let path = visualizer.out_path(&args.visualize_dir, backend);
+		let rendered = visual::render(&grid, backend, &path, args.visualize_scale)?;
+		reporter.label(&visualizer.label());
+		match rendered {
+			Some(text) => print!("{}", text),
+			None => reporter.detail(&format!("wrote {}", path.display())),
+		}
+	}
 
 	Ok(())
 }
+
+fn record(
+	args: &Args,
+	reporter: &dyn Reporter,
+	animation: visual::Animation,
+	challenges: Vec<&'static Challenge>,
+) -> Result<(), anyhow::Error> {
+	for challenge in challenges.into_iter().filter(|c| !c.skip) {
+		let name = format!(
+			"{}-{:0>2}-{}",
+			challenge.year, challenge.day, challenge.part
+		);
+		let path = animation.out_path(&args.visualize_dir, &name);
+		let recorder = visual::Recorder::new(
+			animation,
+			path.clone(),
+			args.visualize_scale,
+			args.record_every,
+		);
+		let (result, frames) = runner::record(challenge, recorder)?;
+		reporter.run(&result);
+		if args.show_output {
+			reporter.output(&result.output);
+		}
+		let theme = reporter.theme();
+		match frames {
+			Ok(0) => reporter.detail("no frames recorded"),
+			Ok(n) => {
+				reporter.detail(&format!("wrote {} frames to {}", n, path.display()))
+			}
+			Err(err) => {
+				reporter.detail(&theme.fail(&format!("Recording failed - {}", err)))
+			}
+		}
+	}
+
+	Ok(())
+}
+
+fn unlock(args: &Args, reporter: &dyn Reporter) -> Result<(), anyhow::Error> {
+	let Some(Command::Unlock { site, now, no_open }) = &args.command else {
+		return Ok(());
+	};
+	let clock: Box<dyn Clock> = match now {
+		Some(now) => Box::new(SimulatedClock::new(*now)),
+		None => Box::new(SystemClock),
+	};
+	let (year, day) = match (args.year, args.day) {
+		(Some(year), Some(day)) => (year, day),
+		_ => unlock::next_unlock(clock.now()),
+	};
+	let client = site.client()?;
+	unlock::prepare(clock.as_ref(), &client, year, day, !no_open, reporter)
+}
+
+fn leaderboard(
+	args: &Args,
+	reporter: &dyn Reporter,
+) -> Result<(), anyhow::Error> {
+	let Some(Command::Leaderboard { id, file, site }) = &args.command else {
+		return Ok(());
+	};
+	let json = match (file, id) {
+		(Some(file), _) => leaderboard::load(file)?,
+		(None, Some(id)) => {
+			let year = args
+				.year
+				.unwrap_or_else(|| unlock::latest_event(SystemTime::now()));
+			leaderboard::fetch(&site.client()?, year, *id)?
+		}
+		(None, None) => return Err(anyhow!("Give a leaderboard --id or --file")),
+	};
+	let board = leaderboard::Leaderboard::parse(&json)?;
+	reporter.heading(&format!("Leaderboard {}", board.event));
+	print!("{}", leaderboard::render(&board, reporter.theme()));
+	Ok(())
+}
@@ -1,2 +0,0 @@
-pub mod counter;
-pub mod two_way;
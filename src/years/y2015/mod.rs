@@ -1,13 +0,0 @@
-use crate::common::{Challenge, ChallengeResult};
-use anyhow::anyhow;
-use inventory::submit;
-
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
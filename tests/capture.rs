@@ -0,0 +1,63 @@
+//! Capturing what challenges print.
+
+// shadows print! and println! the same way year crates do
+#[allow(unused_imports)]
+#[macro_use]
+extern crate aoc_core;
+
+use std::process::Command;
+
+use adventofcode::capture::{self, capture};
+
+#[test]
+fn captures_prints() {
+	let (res, output) = capture(|| {
+		capture::write(format_args!("{}\n", 1));
+		2
+	});
+	assert_eq!(res, 2);
+	assert_eq!(output, "1\n");
+}
+
+#[test]
+fn restores_after_panic() {
+	let ((), output) = capture(|| {
+		let panicked = std::panic::catch_unwind(|| {
+			capture(|| {
+				capture::write(format_args!("before "));
+				panic!("oops")
+			})
+		});
+		assert!(panicked.is_err());
+		// goes to the outer capture, not the one that panicked
+		capture::write(format_args!("after"));
+	});
+	assert_eq!(output, "after");
+}
+
+/// Set when this test binary runs [`macros_stay_off_stdout`] in a child
+/// process, whose stdout isn't captured by the test harness.
+const CHILD_ENV: &str = "AOC_CAPTURE_CHILD";
+
+#[test]
+fn macros_stay_off_stdout() {
+	if std::env::var_os(CHILD_ENV).is_some() {
+		let ((), output) = capture(|| {
+			print!("captured ");
+			println!("{}", "line");
+		});
+		assert_eq!(output, "captured line\n");
+		println!("not captured");
+		return;
+	}
+
+	let child = Command::new(std::env::current_exe().unwrap())
+		.env(CHILD_ENV, "1")
+		.args(["--exact", "macros_stay_off_stdout", "--nocapture"])
+		.output()
+		.unwrap();
+	let stdout = String::from_utf8_lossy(&child.stdout);
+	assert!(child.status.success(), "{}", stdout);
+	assert!(stdout.contains("not captured\n"));
+	assert!(!stdout.contains("captured line"));
+}
@@ -0,0 +1,14 @@
+//! Helpers shared by the integration tests.
+
+use std::path::PathBuf;
+
+/// A fresh, empty directory named `name` for a test to work in. It's under a
+/// directory of this process's own, so concurrent test runs don't clash.
+pub fn scratch_dir(name: &str) -> PathBuf {
+	let dir = std::env::temp_dir()
+		.join(format!("aoc-tests-{}", std::process::id()))
+		.join(name);
+	let _ = std::fs::remove_dir_all(&dir);
+	std::fs::create_dir_all(&dir).unwrap();
+	dir
+}
@@ -0,0 +1,64 @@
+//! Encrypting and decrypting stored files.
+
+use std::process::Command;
+
+mod common;
+
+use adventofcode::crypt;
+
+#[test]
+fn round_trip() {
+	let salt = crypt::salt();
+	let encrypted = crypt::encrypt(b"1abc2\n", "secret", salt).unwrap();
+	assert!(crypt::is_encrypted(&encrypted));
+	assert!(!encrypted.windows(5).any(|w| w == b"1abc2"));
+	assert_eq!(crypt::decrypt(&encrypted, "secret").unwrap(), b"1abc2\n");
+
+	// a fresh nonce every time, even with the same salt
+	let again = crypt::encrypt(b"1abc2\n", "secret", salt).unwrap();
+	assert_ne!(encrypted, again);
+}
+
+#[test]
+fn wrong_passphrase() {
+	let encrypted = crypt::encrypt(b"54331\n", "secret", crypt::salt()).unwrap();
+	assert!(crypt::decrypt(&encrypted, "guess").is_err());
+	assert!(crypt::decrypt(b"54331\n", "secret").is_err());
+}
+
+/// Encrypting and decrypting in place through the binary, in a scratch
+/// directory.
+#[test]
+fn converts_files() {
+	let dir = common::scratch_dir("crypt-convert");
+	std::fs::create_dir_all(dir.join("inputs/2023")).unwrap();
+	std::fs::write(dir.join("inputs/2023/01.txt"), "1abc2\n").unwrap();
+	let run = |command: &str, passphrase: &str| {
+		Command::new(env!("CARGO_BIN_EXE_adventofcode"))
+			.current_dir(&dir)
+			.env(crypt::PASSPHRASE_ENV, passphrase)
+			.arg(command)
+			.status()
+			.unwrap()
+	};
+	let files = || std::fs::read_dir(dir.join("inputs/2023")).unwrap().count();
+
+	// an empty passphrase is refused rather than used
+	assert!(!run("encrypt", " \n").success());
+	assert_eq!(
+		std::fs::read(dir.join("inputs/2023/01.txt")).unwrap(),
+		b"1abc2\n"
+	);
+
+	assert!(run("encrypt", "secret").success());
+	let encrypted = std::fs::read(dir.join("inputs/2023/01.txt")).unwrap();
+	assert!(crypt::is_encrypted(&encrypted));
+	assert_eq!(files(), 1);
+
+	assert!(run("decrypt", "secret").success());
+	assert_eq!(
+		std::fs::read(dir.join("inputs/2023/01.txt")).unwrap(),
+		b"1abc2\n"
+	);
+	assert_eq!(files(), 1);
+}
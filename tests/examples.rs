@@ -0,0 +1,88 @@
+//! Runs the challenge registry under `cargo test`.
+//!
+//! Every example in a challenge's `unit_tests` becomes its own test, named
+//! like `2023::day10::part1::example2` so it can be filtered as usual.
+//! Challenges with a known answer also get an ignored `real` test that runs
+//! them on their input, included with `cargo test -- --include-ignored`.
+//!
+//! Examples a solution is known to get wrong are listed in
+//! [`EXPECTED_FAILURES`]: they pass while they fail, and fail once they pass
+//! so they get taken off the list.
+
+use libtest_mimic::{Arguments, Failed, Trial};
+
+use adventofcode::{all_challenges, capture, store, Challenge};
+
+/// The year, day, part and example index of examples known to fail.
+const EXPECTED_FAILURES: &[(usize, usize, usize, usize)] = &[
+	// counts the wrong number of enclosed tiles
+	(2023, 10, 2, 1),
+	(2023, 10, 2, 2),
+];
+
+fn name(challenge: &Challenge, test: &str) -> String {
+	format!(
+		"{}::day{:0>2}::part{}::{}",
+		challenge.year, challenge.day, challenge.part, test
+	)
+}
+
+fn example(challenge: &'static Challenge, i: usize) -> Trial {
+	let (input, expected) = challenge.unit_tests[i];
+	let expected_failure = EXPECTED_FAILURES.contains(&(
+		challenge.year,
+		challenge.day,
+		challenge.part,
+		i,
+	));
+	Trial::test(name(challenge, &format!("example{}", i)), move || {
+		let (res, output) = capture::capture(|| (challenge.f)(input));
+		let res = match res {
+			Ok(got) if got == expected => Ok(()),
+			Ok(got) => Err(format!(
+				"expected {}, got {} for input:\n{}\n{}",
+				expected, got, input, output
+			)),
+			Err(err) => Err(format!("{}\n{}", err, output)),
+		};
+		match res {
+			Err(_) if expected_failure => Ok(()),
+			Ok(()) if expected_failure => {
+				Err("passes now, take it off EXPECTED_FAILURES".into())
+			}
+			res => res.map_err(Failed::from),
+		}
+	})
+	.with_ignored_flag(challenge.skip)
+}
+
+fn real(challenge: &'static Challenge) -> Trial {
+	Trial::test(name(challenge, "real"), move || {
+		let result = challenge.run_on_file().map_err(Failed::from)?;
+		match (&result.result, result.expected) {
+			(Err(err), _) => Err(format!("{}\n{}", err, result.output).into()),
+			(Ok(got), Some(expected)) if *got != expected => {
+				Err(format!("expected {}, got {}", expected, got).into())
+			}
+			_ => Ok(()),
+		}
+	})
+	.with_ignored_flag(true)
+}
+
+fn main() {
+	let args = Arguments::from_args();
+
+	let mut trials = Vec::new();
+	for challenge in all_challenges().unwrap() {
+		for i in 0..challenge.unit_tests.len() {
+			trials.push(example(challenge, i));
+		}
+		if !challenge.skip && matches!(store::known_answer(challenge), Ok(Some(_)))
+		{
+			trials.push(real(challenge));
+		}
+	}
+
+	libtest_mimic::run(&args, trials).exit();
+}
@@ -0,0 +1,90 @@
+//! Reading and appending to the solve journal.
+
+use std::{path::PathBuf, process::Command};
+
+mod common;
+
+use adventofcode::journal::{self, Event, Record};
+
+/// A journal path in a fresh directory, so appending creates it.
+fn journal(name: &str) -> PathBuf {
+	common::scratch_dir(name).join(".aoc/journal.jsonl")
+}
+
+fn record(part: Option<usize>, event: Event, timestamp: u64) -> Record {
+	Record {
+		year: 2023,
+		day: 1,
+		part,
+		event,
+		timestamp,
+	}
+}
+
+#[test]
+fn round_trip() {
+	let path = journal("journal-round-trip");
+	assert!(journal::load_from(&path).unwrap().is_empty());
+
+	let records = vec![
+		record(None, Event::Scaffolded, 100),
+		record(Some(1), Event::ExamplesPassed, 200),
+		record(Some(1), Event::Answered, 300),
+	];
+	journal::record_to(&path, records.clone()).unwrap();
+	assert_eq!(journal::load_from(&path).unwrap(), records);
+	assert!(std::fs::read_to_string(&path).unwrap().starts_with(
+		"{\"year\":2023,\"day\":1,\"part\":null,\"event\":\"scaffolded\""
+	));
+}
+
+#[test]
+fn appends_first_events_only() {
+	let path = journal("journal-append");
+	journal::record_to(&path, vec![record(None, Event::Scaffolded, 100)])
+		.unwrap();
+	journal::record_to(
+		&path,
+		vec![
+			// already there, and twice in the same batch
+			record(None, Event::Scaffolded, 150),
+			record(Some(2), Event::Answered, 400),
+			record(Some(2), Event::Answered, 500),
+		],
+	)
+	.unwrap();
+
+	assert_eq!(
+		journal::load_from(&path).unwrap(),
+		vec![
+			record(None, Event::Scaffolded, 100),
+			record(Some(2), Event::Answered, 400),
+		]
+	);
+}
+
+/// Only real solving runs through the binary are journaled, not test-only or
+/// CI runs.
+#[test]
+fn skips_test_and_ci_runs() {
+	let dir = common::scratch_dir("journal-runs");
+	let path = dir.join(".aoc/journal.jsonl");
+	let run = |args: &[&str]| {
+		Command::new(env!("CARGO_BIN_EXE_adventofcode"))
+			.current_dir(&dir)
+			.args(["-y", "2015", "-d", "1"])
+			.args(args)
+			.output()
+			.unwrap();
+	};
+
+	run(&["--test-only"]);
+	run(&["--ci"]);
+	assert!(!path.exists());
+
+	run(&[]);
+	assert!(journal::load_from(&path)
+		.unwrap()
+		.iter()
+		.any(|r| r.event == Event::ExamplesPassed));
+}
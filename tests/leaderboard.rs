@@ -0,0 +1,48 @@
+//! Reading and rendering a saved private leaderboard.
+
+use adventofcode::{
+	leaderboard::{self, Leaderboard},
+	report::Theme,
+};
+
+const FIXTURE: &str = include_str!("fixtures/leaderboard.json");
+
+#[test]
+fn parses_members() {
+	let board = Leaderboard::parse(FIXTURE).unwrap();
+	assert_eq!(board.event, "2023");
+	assert_eq!(board.members.len(), 4);
+
+	let owner = &board.members["101"];
+	assert_eq!(owner.stars_on(1), 2);
+	assert_eq!(owner.stars_on(3), 1);
+	assert_eq!(owner.stars_on(4), 0);
+	assert_eq!(board.members["303"].name(), "(anonymous user #303)");
+}
+
+#[test]
+fn ranks_by_local_score() {
+	let board = Leaderboard::parse(FIXTURE).unwrap();
+	let ids = board.ranked().iter().map(|m| m.id).collect::<Vec<u64>>();
+	assert_eq!(ids, [101, 202, 303, 404]);
+}
+
+#[test]
+fn renders_stars_per_day() {
+	let board = Leaderboard::parse(FIXTURE).unwrap();
+	let rendered = leaderboard::render(&board, Theme::plain());
+	let lines = rendered.lines().collect::<Vec<&str>>();
+	assert_eq!(lines.len(), 6);
+	assert_eq!(lines[0], "               1111111111222222");
+	assert_eq!(lines[1], "      1234567890123456789012345");
+	assert_eq!(lines[2], "1) 13 ★★☆······················ Owner (5 ★)");
+	assert_eq!(
+		lines[4],
+		"3)  1 ☆························ (anonymous user #303) (1 ★)"
+	);
+}
+
+#[test]
+fn rejects_invalid_json() {
+	assert!(Leaderboard::parse("<html>Not logged in</html>").is_err());
+}
@@ -0,0 +1,23 @@
+//! Which task's progress line a thread reports to.
+
+use adventofcode::progress::{self, Progress};
+
+fn current() -> Option<String> {
+	progress::current().map(|handle| handle.label().to_string())
+}
+
+#[test]
+fn nested_task_restores_outer() {
+	let progress = Progress::hidden();
+	assert_eq!(current(), None);
+	let outer = progress.start("outer".to_string());
+	assert_eq!(current().as_deref(), Some("outer"));
+	{
+		let _inner = progress.start("inner".to_string());
+		assert_eq!(current().as_deref(), Some("inner"));
+	}
+	// the outer task is still running, so its line is current again
+	assert_eq!(current().as_deref(), Some("outer"));
+	drop(outer);
+	assert_eq!(current(), None);
+}
@@ -0,0 +1,62 @@
+//! Converting a saved puzzle page to markdown and rendering it.
+
+use adventofcode::{puzzle, report::Theme};
+
+const FIXTURE: &str = include_str!("fixtures/puzzle.html");
+
+const MARKDOWN: &str = "\
+## --- Day 1: Trebuchet?! ---
+
+Something is wrong with global snow production, and you've been selected to take a look. The Elves have even given you a [map](/2023/map); on it, they've used *stars* to mark the top fifty locations.
+
+Each line is `a*b` or \\[1 \\* 2] & <more>.
+
+For example:
+
+```
+1abc2
+a*b[c]
+```
+
+- In this example, adding these together produces `142`.
+
+## --- Part Two ---
+
+Your calculation isn't quite right.
+";
+
+#[test]
+fn converts_articles() {
+	assert_eq!(puzzle::html_to_markdown(FIXTURE), MARKDOWN);
+}
+
+#[test]
+fn renders_plain() {
+	let rendered = puzzle::render(MARKDOWN, Theme::plain());
+	assert!(rendered.starts_with("--- Day 1: Trebuchet?! ---\n\n"));
+	assert!(rendered.contains("you a map; on it, they've used stars to mark"));
+	// literal markup characters stay as they were
+	assert!(rendered.contains("Each line is a*b or [1 * 2] & <more>."));
+	assert!(rendered.contains("\n    1abc2\n    a*b[c]\n\n"));
+	assert!(
+		rendered.contains("- In this example, adding these together produces 142.")
+	);
+}
+
+#[test]
+fn renders_styles() {
+	let rendered = puzzle::render(MARKDOWN, Theme::new(true));
+	let t = Theme::new(true);
+	assert!(rendered.contains(&t.emphasis("stars")));
+	assert!(rendered.contains(&t.code("a*b")));
+	assert!(rendered.contains(&format!("    {}\n", t.code("1abc2"))));
+	assert!(rendered.contains(" or [1 * 2] & <more>."));
+}
+
+#[test]
+fn truncated_page() {
+	let html = "<article><p>Cut <em>short</em> <";
+	assert_eq!(puzzle::html_to_markdown(html), "Cut *short* <\n");
+	assert_eq!(puzzle::html_to_markdown("<"), "\n");
+	assert_eq!(puzzle::html_to_markdown("<article><p"), "<p\n");
+}
@@ -0,0 +1,88 @@
+//! Regenerating the README's progress section through the binary.
+
+use std::{
+	path::{Path, PathBuf},
+	process::Command,
+};
+
+use adventofcode::readme::{END, START};
+
+mod common;
+
+/// A README with an outdated progress section, in a fresh directory.
+fn readme(name: &str) -> PathBuf {
+	let path = common::scratch_dir(name).join("README.md");
+	std::fs::write(
+		&path,
+		format!(
+			"# Advent of Code\n\n{}\n- [ ] 1999\n{}\n\nBye\n",
+			START, END
+		),
+	)
+	.unwrap();
+	path
+}
+
+fn run(path: &Path, args: &[&str]) -> bool {
+	Command::new(env!("CARGO_BIN_EXE_adventofcode"))
+		.current_dir(path.parent().unwrap())
+		.args(["readme", "--path", "README.md"])
+		.args(args)
+		.status()
+		.unwrap()
+		.success()
+}
+
+#[test]
+fn keeps_every_year() {
+	let path = readme("readme-every-year");
+	assert!(run(&path, &[]));
+	let updated = std::fs::read_to_string(&path).unwrap();
+	assert!(updated.contains("] 2015 - "));
+	assert!(updated.contains("] 2023 - "));
+	assert!(!updated.contains("1999"));
+	assert!(updated.ends_with("\nBye\n"));
+}
+
+#[test]
+fn rejects_selection() {
+	let path = readme("readme-selection");
+	assert!(run(&path, &[]));
+	let before = std::fs::read_to_string(&path).unwrap();
+	assert!(!run(&path, &["-y", "2023"]));
+	// the other years are still there
+	assert_eq!(std::fs::read_to_string(&path).unwrap(), before);
+	assert!(before.contains("] 2015 - "));
+}
+
+/// A build with only some years refuses to regenerate the section, rather
+/// than dropping the years it leaves out.
+#[test]
+fn partial_build() {
+	let path = readme("readme-partial");
+	assert!(run(&path, &[]));
+	let before = std::fs::read_to_string(&path).unwrap();
+
+	let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("partial");
+	let built = Command::new(env!("CARGO"))
+		.args(["build", "--quiet", "--bin", "adventofcode"])
+		.args(["--no-default-features", "--features", "y2023"])
+		.arg("--manifest-path")
+		.arg(Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml"))
+		.arg("--target-dir")
+		.arg(&target_dir)
+		.status()
+		.unwrap();
+	assert!(built.success());
+
+	let binary = target_dir
+		.join("debug")
+		.join(format!("adventofcode{}", std::env::consts::EXE_SUFFIX));
+	let status = Command::new(binary)
+		.current_dir(path.parent().unwrap())
+		.args(["readme", "--path", "README.md"])
+		.status()
+		.unwrap();
+	assert!(!status.success());
+	assert_eq!(std::fs::read_to_string(&path).unwrap(), before);
+}
@@ -0,0 +1,40 @@
+//! Recording frames into animations.
+
+use adventofcode::visual::{
+	record::{self, recording},
+	Animation, Grid, Recorder,
+};
+
+mod common;
+
+fn recorder(dir: &std::path::Path) -> Recorder {
+	Recorder::new(Animation::Frames, dir.to_path_buf(), 1, 1)
+}
+
+#[test]
+fn records_frames() {
+	let dir = common::scratch_dir("record-frames").join("frames");
+	let ((), frames) = recording(recorder(&dir), || {
+		for _ in 0..3 {
+			record::record(|| Grid::new(2, 2));
+		}
+	});
+	assert_eq!(frames.unwrap(), 3);
+	assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 3);
+}
+
+#[test]
+fn restores_after_panic() {
+	let dir = common::scratch_dir("record-restores");
+	let (outer, inner) = (dir.join("outer"), dir.join("inner"));
+	let ((), frames) = recording(recorder(&outer), || {
+		let panicked = std::panic::catch_unwind(|| {
+			recording(recorder(&inner), || panic!("oops"))
+		});
+		assert!(panicked.is_err());
+		// goes to the outer recording, not the one that panicked
+		record::record(|| Grid::new(2, 2));
+	});
+	assert_eq!(frames.unwrap(), 1);
+	assert!(!inner.exists());
+}
@@ -0,0 +1,44 @@
+//! Finding stored inputs and answers, on disk and embedded.
+
+use std::path::Path;
+
+use adventofcode::{embedded, stored};
+
+mod common;
+
+#[test]
+fn numbers() {
+	assert_eq!(stored::number(Path::new("01.txt")), Some(1));
+	assert_eq!(stored::number(Path::new("inputs/2023")), Some(2023));
+	assert_eq!(stored::number(Path::new("notes.md")), None);
+	assert_eq!(stored::number(Path::new("01.txt.tmp")), None);
+}
+
+#[test]
+fn files() {
+	let root = common::scratch_dir("stored-files");
+	std::fs::create_dir_all(root.join("2023/25")).unwrap();
+	std::fs::create_dir_all(root.join("old")).unwrap();
+	for file in ["2023/02.txt", "2023/01.txt", "2023/README.md", "old/01.txt"] {
+		std::fs::write(root.join(file), "").unwrap();
+	}
+	std::fs::write(root.join("2015"), "").unwrap();
+
+	assert_eq!(
+		stored::files(&root).unwrap(),
+		[
+			((2023, 1), root.join("2023/01.txt")),
+			((2023, 2), root.join("2023/02.txt")),
+		]
+	);
+	assert!(stored::files(&root.join("missing")).unwrap().is_empty());
+}
+
+#[test]
+fn finds_embedded() {
+	static FILES: &[((usize, usize), &[u8])] =
+		&[((2015, 1), b"(()"), ((2023, 1), b"1abc2\n")];
+	assert_eq!(embedded::find(FILES, 2023, 1), Some(&b"1abc2\n"[..]));
+	assert_eq!(embedded::find(FILES, 2023, 2), None);
+	assert_eq!(embedded::find(&[], 2015, 1), None);
+}
@@ -0,0 +1,163 @@
+//! The unlock helper against a simulated clock and a local stand-in for the
+//! site.
+
+use std::{
+	io::{BufRead, BufReader, Write},
+	net::TcpListener,
+	path::Path,
+	process::Command,
+	time::{Duration, UNIX_EPOCH},
+};
+
+use adventofcode::{
+	fetch::Client,
+	list,
+	report::{self, ColorMode, ReporterKind},
+	unlock::{self, Clock, SimulatedClock},
+};
+
+mod common;
+
+/// Serves the body of each path in `pages` and a 404 for anything else,
+/// returning the base URL. Also checks that the session cookie is sent.
+fn stand_in(pages: &'static [(&'static str, &'static str)]) -> String {
+	let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+	let base_url = format!("http://{}", listener.local_addr().unwrap());
+	std::thread::spawn(move || {
+		for stream in listener.incoming() {
+			let mut stream = stream.unwrap();
+			let mut lines = BufReader::new(&stream)
+				.lines()
+				.map(|line| line.unwrap())
+				.take_while(|line| !line.is_empty())
+				.collect::<Vec<String>>();
+			let request = lines.remove(0);
+			let authorized = lines.iter().any(|l| l == "Cookie: session=secret");
+			let page = pages
+				.iter()
+				.find(|(path, _)| request == format!("GET {} HTTP/1.1", path));
+			let response = match page {
+				Some((_, body)) if authorized => format!(
+					"HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}",
+					body.len(),
+					body
+				),
+				_ => "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n".to_string(),
+			};
+			stream.write_all(response.as_bytes()).unwrap();
+		}
+	});
+	base_url
+}
+
+fn at(secs: u64) -> std::time::SystemTime {
+	UNIX_EPOCH + Duration::from_secs(secs)
+}
+
+#[test]
+fn unlocks_at_midnight_eastern() {
+	// 2023-12-01T05:00:00Z
+	assert_eq!(unlock::unlock_time(2023, 1), at(1701406800));
+	assert_eq!(unlock::unlock_time(2023, 25), at(1701406800 + 24 * 86400));
+}
+
+#[test]
+fn next_unlock() {
+	// the second before the first unlock, and the moment of it
+	assert_eq!(unlock::next_unlock(at(1701406799)), (2023, 1));
+	assert_eq!(unlock::next_unlock(at(1701406800)), (2023, 2));
+	// midsummer, and after the last day
+	assert_eq!(unlock::next_unlock(at(1688169600)), (2023, 1));
+	assert_eq!(unlock::next_unlock(at(1703487600)), (2024, 1));
+	// events have 12 days since 2025
+	assert_eq!(unlock::next_unlock(at(1765515600 - 1)), (2025, 12));
+	assert_eq!(unlock::next_unlock(at(1765515600)), (2026, 1));
+	assert_eq!(unlock::latest_event(at(1765515600)), 2025);
+}
+
+#[test]
+fn stars_per_event() {
+	assert_eq!(unlock::days_in(2024), 25);
+	assert_eq!(list::stars_in(2024), 50);
+	assert_eq!(unlock::days_in(2025), 12);
+	assert_eq!(list::stars_in(2025), 24);
+}
+
+#[test]
+fn waits_until_unlock() {
+	let clock = SimulatedClock::new(at(1701406800 - 3 * 3600 - 5));
+	let reporter = report::reporter(ReporterKind::Plain, ColorMode::Never);
+	unlock::wait(&clock, 2023, 1, reporter.as_ref());
+	assert_eq!(clock.now(), at(1701406800));
+}
+
+#[test]
+fn downloads_input() {
+	let base_url = stand_in(&[("/2023/day/1/input", "1abc2\n")]);
+	let client = Client::new(&base_url, Some("secret".to_string()));
+	assert_eq!(client.input(2023, 1).unwrap(), "1abc2\n");
+	assert!(client.input(2023, 2).is_err());
+
+	let client = Client::new(&base_url, None);
+	assert!(client.input(2023, 1).is_err());
+}
+
+const PAGE: &str = "<html><main><article class=\"day-desc\">\
+	<h2>--- Day 1: Trebuchet?! ---</h2><p>Calibrate <em>it</em>.</p>\
+	</article></main></html>";
+
+fn read(path: impl AsRef<Path>) -> String {
+	std::fs::read_to_string(path).unwrap()
+}
+
+/// The whole unlock flow through the binary, in a scratch repository with
+/// an empty year crate.
+#[test]
+fn prepares_day() {
+	let base_url =
+		stand_in(&[("/2023/day/1/input", "1abc2\n"), ("/2023/day/1", PAGE)]);
+	let dir = common::scratch_dir("unlock-prepare");
+	let crate_dir = dir.join("crates/y2023");
+	std::fs::create_dir_all(crate_dir.join("src")).unwrap();
+	std::fs::write(
+		crate_dir.join("Cargo.toml"),
+		"[package]\nname = \"aoc-2023\"\n",
+	)
+	.unwrap();
+	std::fs::write(
+		crate_dir.join("src/lib.rs"),
+		"//! Solutions to Advent of Code 2023.\n",
+	)
+	.unwrap();
+
+	let unlock = || {
+		Command::new(env!("CARGO_BIN_EXE_adventofcode"))
+			.current_dir(&dir)
+			.args(["unlock", "-y", "2023", "-d", "1", "--no-open"])
+			.args(["--base-url", &base_url, "--session", "secret"])
+			.args(["--now", "2023-12-01T05:00:00Z"])
+			.status()
+			.unwrap()
+	};
+	assert!(unlock().success());
+
+	assert!(read(crate_dir.join("src/lib.rs")).contains("\nmod day01;\n"));
+	assert!(read(crate_dir.join("Cargo.toml")).contains("aoc-core.workspace"));
+	assert!(read(crate_dir.join("src/day01.rs")).contains("day: 1,"));
+	assert_eq!(read(dir.join("inputs/2023/01.txt")), "1abc2\n");
+	assert_eq!(read(dir.join("puzzles/2023/01.html")), PAGE);
+	assert!(read(crate_dir.join("src/day01.md"))
+		.starts_with("## --- Day 1: Trebuchet?! ---\n\nCalibrate *it*."));
+	assert!(read(dir.join(".aoc/journal.jsonl")).contains("\"scaffolded\""));
+
+	// a second run keeps the existing module and input
+	std::fs::write(dir.join("inputs/2023/01.txt"), "edited\n").unwrap();
+	assert!(unlock().success());
+	assert_eq!(read(dir.join("inputs/2023/01.txt")), "edited\n");
+	assert_eq!(
+		read(crate_dir.join("src/lib.rs"))
+			.matches("mod day01;")
+			.count(),
+		1
+	);
+}